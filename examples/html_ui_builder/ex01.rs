@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use bevyex_lib::html_ui_builder::HtmlCssUIBuilder;
+use bevyex_lib::html_ui_builder::{HtmlCssUIBuilder, HtmlCssUIPlugin};
 
 fn main() {
     App::new()
-        .add_plugins(DefaultPlugins)
+        .add_plugins((DefaultPlugins, HtmlCssUIPlugin))
         .add_systems(Startup, setup_html_ui)
+        .add_systems(Update, toggle_theme)
         .run();
 }
 
@@ -17,47 +18,79 @@ fn setup_html_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
     <html>
         <head>
             <style>
+                :root {
+                    --primary: #007bff;
+                    --primary-hover: #0056b3;
+                    --radius: 6px;
+                }
+
                 .container {
                     background-color:rgb(202, 1, 1);
                     width: 800px;
                     height: 600px;
+                    display: flex;
+                    justify-content: center;
+                    align-items: center;
                 }
                 
                 .title {
                     font-size: 32px;
+                    font-family: Prompt, sans-serif;
+                    font-weight: bold;
                     color: #333333;
                     text-align: center;
                 }
                 
                 .button {
-                    background-color: #007bff;
+                    background-color: var(--primary);
                     color: white;
-                    border-radius: 6px;
+                    border-radius: var(--radius, 4px);
                     width: 200px;
                     height: 50px;
                     font-size: 16px;
+                    display: flex;
+                    justify-content: center;
+                    align-items: center;
                 }
                 
                 .button:hover {
-                    background-color: #0056b3;
+                    background-color: var(--primary-hover);
+                }
+
+                .button:active {
+                    background-color: #003f80;
+                }
+
+                .button:focus-visible {
+                    outline: 2px solid #ffbf00;
+                    outline-offset: 2px;
                 }
                 
                 .card {
                     background-color: white;
+                    border: 1px solid #dddddd;
                     padding: 16px;
                     margin: 12px;
                     border-radius: 8px;
                     box-shadow: 0 2px 4px rgba(0,0,0,0.1);
                     width: 300px;
+                    display: flex;
+                    flex-direction: column;
+                    align-items: center;
+                    gap: 8px;
                 }
 
                 .card2 {
-                    background-color: red;
+                    background: linear-gradient(135deg, #ff512f, #dd2476);
                     padding: 16px;
                     margin: 12px;
                     border-radius: 8px;
                     box-shadow: 0 2px 4px rgba(0,0,0,0.1);
                     width: 300px;
+                    display: flex;
+                    flex-direction: column;
+                    align-items: center;
+                    gap: 8px;
                 }
                 
                 #main-content {
@@ -72,7 +105,7 @@ fn setup_html_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
                 <div id="main-content">
                     <h1 class="title">ยินดีต้อนรับสู่แอปพลิเคชัน</h1>
                     <div class="card">
-                        <p>นี่คือการ์ดตัวอย่าง</p>
+                        <p>นี่คือ<b>การ์ด</b>ตัวอย่าง</p>
                         <button class="button">คลิกที่นี่</button>
                     </div>
                     <div id="card2" class="card2">
@@ -90,4 +123,27 @@ fn setup_html_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
     if let Ok(elements) = ui_builder.parse_and_build(html_content) {
         ui_builder.spawn_bevy_ui(&mut commands, &asset_server, &elements);
     }
+    commands.insert_resource(ui_builder);
+}
+
+// กด T เพื่อสลับ theme ผ่าน CSS variables
+fn toggle_theme(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut ui_builder: ResMut<HtmlCssUIBuilder>,
+) {
+    if !keys.just_pressed(KeyCode::KeyT) {
+        return;
+    }
+    if ui_builder.variables().is_empty() {
+        ui_builder.set_variable("--primary", "#2e7d32");
+        ui_builder.set_variable("--primary-hover", "#1b5e20");
+        ui_builder.set_variable("--radius", "16px");
+    } else {
+        for name in ["--primary", "--primary-hover", "--radius"] {
+            ui_builder.remove_variable(name);
+        }
+    }
+    ui_builder.retheme(&mut commands, &asset_server);
 }
@@ -0,0 +1,358 @@
+use super::css::{CssLength, DEFAULT_FONT_SIZE, GradientShape, RadialExtent};
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderRef};
+use std::f32::consts::SQRT_2;
+
+// จำนวน color stops สูงสุดที่ shader รับได้
+pub const MAX_GRADIENT_STOPS: usize = 8;
+
+pub const GRADIENT_SHADER_PATH: &str = "embedded://bevyex_lib/html_ui_builder/gradient.wgsl";
+
+// หนึ่ง layer ของ background-image / border-image (วาดเป็น child ที่อยู่ใต้ children อื่น)
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackgroundLayer {
+    Image { path: String, fit: ImageFit },
+    Gradient(BackgroundGradient),
+}
+
+// วิธีวาดภาพให้พอดีกับกล่อง (background-size / object-fit / border-image)
+#[derive(Component, Debug, Clone, PartialEq)]
+pub enum ImageFit {
+    // ขนาดจริงของภาพ (background-repeat: no-repeat, object-fit: none)
+    Natural,
+    // ปูภาพขนาดจริงตามแกนที่ repeat (ค่าเริ่มต้นของ background-image)
+    Repeat { x: bool, y: bool },
+    // ยืดเต็มกล่อง (object-fit: fill)
+    Stretch,
+    // cover / contain วางไว้กลางกล่องเหมือน background-position: center
+    Cover,
+    Contain,
+    // background-size: <width> <height> — auto ใช้สัดส่วนของภาพ
+    Size(Val, Val),
+    // border-image: slice (top, right, bottom, left) เป็น px หรือ % ของภาพ
+    Sliced { slices: [Val; 4], tile: bool },
+}
+
+impl ImageFit {
+    // Node และ image mode ตอน spawn — Cover / Contain / Size / Sliced ปรับต่อใน fit_background_images
+    pub fn layer_bundle(&self, image: Handle<Image>) -> (Node, ImageNode) {
+        let (width, height, image_mode) = match self {
+            ImageFit::Natural | ImageFit::Repeat { x: false, y: false } => {
+                (Val::Auto, Val::Auto, NodeImageMode::Auto)
+            }
+            ImageFit::Repeat { x, y } => (
+                if *x { Val::Percent(100.0) } else { Val::Auto },
+                if *y { Val::Percent(100.0) } else { Val::Auto },
+                NodeImageMode::Tiled {
+                    tile_x: *x,
+                    tile_y: *y,
+                    stretch_value: 1.0,
+                },
+            ),
+            _ => (
+                Val::Percent(100.0),
+                Val::Percent(100.0),
+                NodeImageMode::Stretch,
+            ),
+        };
+        (
+            Node {
+                width,
+                height,
+                ..layer_node()
+            },
+            ImageNode {
+                image,
+                image_mode,
+                ..default()
+            },
+        )
+    }
+}
+
+// วางทับ padding box ของ parent โดยไม่กระทบ layout
+pub fn layer_node() -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::ZERO,
+        top: Val::ZERO,
+        width: Val::Percent(100.0),
+        height: Val::Percent(100.0),
+        ..default()
+    }
+}
+
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct BackgroundGradient {
+    pub shape: GradientShape,
+    pub repeating: bool,
+    // ตำแหน่งเป็น px หรือ % ของความยาว gradient (None = กระจายเท่า ๆ กัน)
+    pub stops: Vec<(Color, Option<Val>)>,
+}
+
+impl BackgroundGradient {
+    // คำนวณ uniform ของ shader จากขนาดกล่อง (logical px)
+    pub fn material(&self, size: Vec2) -> GradientMaterial {
+        let (kind, geometry, length) = match &self.shape {
+            GradientShape::Linear(angle) => linear(Vec2::new(angle.sin(), -angle.cos()), size),
+            // เส้น 50% ของ "to top right" ลากผ่านมุมซ้ายบนและขวาล่าง
+            GradientShape::LinearCorner(corner) => linear(
+                Vec2::new(corner.x * size.y, corner.y * size.x).normalize_or_zero(),
+                size,
+            ),
+            GradientShape::Radial {
+                circle,
+                extent,
+                center,
+            } => {
+                let center = *center * size;
+                let radius = radial_radius(*circle, extent, center, size).max(Vec2::splat(0.001));
+                let center = center - size / 2.0;
+                (
+                    1,
+                    Vec4::new(center.x, center.y, radius.x, radius.y),
+                    radius.x,
+                )
+            }
+        };
+
+        let mut material = GradientMaterial {
+            size,
+            kind,
+            repeating: self.repeating as u32,
+            geometry,
+            count: self.stops.len().min(MAX_GRADIENT_STOPS) as u32,
+            ..default()
+        };
+        for (index, ((color, _), offset)) in self
+            .stops
+            .iter()
+            .zip(stop_offsets(&self.stops, length))
+            .take(MAX_GRADIENT_STOPS)
+            .enumerate()
+        {
+            material.colors[index] = Vec4::from_array(color.to_srgba().to_f32_array());
+            material.offsets[index / 4][index % 4] = offset;
+        }
+        material
+    }
+}
+
+fn linear(direction: Vec2, size: Vec2) -> (u32, Vec4, f32) {
+    // ความยาวของเส้น gradient ที่ทำให้ 0% / 100% แตะมุมของกล่องพอดี
+    let length = ((size.x * direction.x).abs() + (size.y * direction.y).abs()).max(0.001);
+    (0, Vec4::new(direction.x, direction.y, length, 0.0), length)
+}
+
+fn radial_radius(circle: bool, extent: &RadialExtent, center: Vec2, size: Vec2) -> Vec2 {
+    // ระยะจาก center ถึงขอบที่ใกล้ / ไกลที่สุดในแต่ละแกน
+    let to_start = center.abs();
+    let to_end = (size - center).abs();
+    let (near, far) = (to_start.min(to_end), to_start.max(to_end));
+    let resolve = |length: &CssLength, base: f32| match length
+        .resolve(DEFAULT_FONT_SIZE, DEFAULT_FONT_SIZE)
+    {
+        Val::Px(px) => px,
+        Val::Percent(percent) => base * percent / 100.0,
+        _ => 0.0,
+    };
+    match (circle, extent) {
+        (true, RadialExtent::ClosestSide) => Vec2::splat(near.min_element()),
+        (true, RadialExtent::FarthestSide) => Vec2::splat(far.max_element()),
+        (true, RadialExtent::ClosestCorner) => Vec2::splat(near.length()),
+        (true, RadialExtent::FarthestCorner) => Vec2::splat(far.length()),
+        (true, RadialExtent::Radius(radius, _)) => Vec2::splat(resolve(radius, size.x)),
+        (false, RadialExtent::ClosestSide) => near,
+        (false, RadialExtent::FarthestSide) => far,
+        // วงรีที่ผ่านมุมโดยคงสัดส่วนของ closest-side / farthest-side
+        (false, RadialExtent::ClosestCorner) => near * SQRT_2,
+        (false, RadialExtent::FarthestCorner) => far * SQRT_2,
+        (false, RadialExtent::Radius(x, y)) => Vec2::new(resolve(x, size.x), resolve(y, size.y)),
+    }
+}
+
+// ตำแหน่งของแต่ละ stop เป็นสัดส่วนของความยาว gradient ตามกฎของ CSS
+pub fn stop_offsets(stops: &[(Color, Option<Val>)], length: f32) -> Vec<f32> {
+    let mut offsets: Vec<Option<f32>> = stops
+        .iter()
+        .map(|(_, position)| match position {
+            Some(Val::Px(px)) => Some(px / length),
+            Some(Val::Percent(percent)) => Some(percent / 100.0),
+            _ => None,
+        })
+        .collect();
+    let Some(last) = offsets.len().checked_sub(1) else {
+        return Vec::new();
+    };
+    offsets[0].get_or_insert(0.0);
+    offsets[last].get_or_insert(1.0);
+
+    // stop ต้องไม่ถอยหลังกว่า stop ก่อนหน้า
+    let mut max = f32::MIN;
+    for offset in offsets.iter_mut().flatten() {
+        *offset = offset.max(max);
+        max = *offset;
+    }
+
+    // stop ที่ไม่ระบุตำแหน่งกระจายเท่า ๆ กันระหว่าง stop ที่รู้ตำแหน่ง
+    let mut start = 0;
+    for end in 1..=last {
+        let Some(end_offset) = offsets[end] else {
+            continue;
+        };
+        let start_offset = offsets[start].unwrap_or_default();
+        let span = (end - start) as f32;
+        for (index, offset) in offsets.iter_mut().enumerate().take(end).skip(start + 1) {
+            *offset =
+                Some(start_offset + (end_offset - start_offset) * (index - start) as f32 / span);
+        }
+        start = end;
+    }
+    offsets.into_iter().map(Option::unwrap_or_default).collect()
+}
+
+// fields ที่ใช้ binding เดียวกันถูกรวมเป็น uniform struct เดียวใน shader
+#[derive(Asset, AsBindGroup, TypePath, Debug, Clone, Default)]
+pub struct GradientMaterial {
+    #[uniform(0)]
+    pub size: Vec2,
+    // 0 = linear, 1 = radial
+    #[uniform(0)]
+    pub kind: u32,
+    #[uniform(0)]
+    pub repeating: u32,
+    // linear: (ทิศทาง x, ทิศทาง y, ความยาว) / radial: (center x, center y, รัศมี x, รัศมี y)
+    // เป็น px เทียบกับกลางกล่อง
+    #[uniform(0)]
+    pub geometry: Vec4,
+    #[uniform(0)]
+    pub count: u32,
+    // สีแบบ sRGB (shader interpolate แล้วแปลงเป็น linear เอง)
+    #[uniform(0)]
+    pub colors: [Vec4; MAX_GRADIENT_STOPS],
+    // ตำแหน่งของ stop ทีละ 4 ค่า
+    #[uniform(0)]
+    pub offsets: [Vec4; MAX_GRADIENT_STOPS / 4],
+}
+
+impl UiMaterial for GradientMaterial {
+    fn fragment_shader() -> ShaderRef {
+        GRADIENT_SHADER_PATH.into()
+    }
+}
+
+// คำนวณ uniform ใหม่เมื่อขนาดของกล่องเปลี่ยน
+pub fn update_gradient_materials(
+    gradients: Query<
+        (
+            &BackgroundGradient,
+            &ComputedNode,
+            &MaterialNode<GradientMaterial>,
+        ),
+        Changed<ComputedNode>,
+    >,
+    mut materials: ResMut<Assets<GradientMaterial>>,
+) {
+    for (gradient, computed, material) in &gradients {
+        if let Some(material) = materials.get_mut(&material.0) {
+            *material = gradient.material(computed.size() * computed.inverse_scale_factor());
+        }
+    }
+}
+
+// cover / contain / background-size / border-image ต้องรู้ขนาดของภาพหลังโหลดเสร็จ
+pub fn fit_background_images(
+    mut layers: Query<(&ImageFit, &mut ImageNode, &mut Node, &ChildOf)>,
+    computed_nodes: Query<&ComputedNode>,
+    images: Res<Assets<Image>>,
+) {
+    for (fit, mut image_node, mut node, child_of) in &mut layers {
+        let Some(image) = images.get(&image_node.image) else {
+            continue;
+        };
+        let Ok(parent) = computed_nodes.get(child_of.parent()) else {
+            continue;
+        };
+        let image_size = image.size_f32();
+        if image_size.min_element() <= 0.0 {
+            continue;
+        }
+        let border = parent.border();
+        let box_size = (parent.size()
+            - Vec2::new(border.left + border.right, border.top + border.bottom))
+            * parent.inverse_scale_factor();
+
+        let mut updated_node = node.clone();
+        let mut rect = image_node.rect;
+        match fit {
+            ImageFit::Cover => {
+                // ตัดภาพให้มีสัดส่วนเท่ากล่อง แล้วยืดเต็มกล่อง
+                let scale = (box_size / image_size).max_element();
+                let visible = (box_size / scale).min(image_size);
+                let min = (image_size - visible) / 2.0;
+                rect = Some(Rect::from_corners(min, min + visible));
+            }
+            ImageFit::Contain => {
+                let size = image_size * (box_size / image_size).min_element();
+                place(&mut updated_node, size, (box_size - size) / 2.0);
+            }
+            ImageFit::Size(width, height) => {
+                let resolve = |val: &Val, base: f32| match val {
+                    Val::Px(px) => Some(*px),
+                    Val::Percent(percent) => Some(base * percent / 100.0),
+                    _ => None,
+                };
+                let ratio = image_size.x / image_size.y;
+                let size = match (resolve(width, box_size.x), resolve(height, box_size.y)) {
+                    (Some(width), Some(height)) => Vec2::new(width, height),
+                    (Some(width), None) => Vec2::new(width, width / ratio),
+                    (None, Some(height)) => Vec2::new(height * ratio, height),
+                    (None, None) => image_size,
+                };
+                place(&mut updated_node, size, Vec2::ZERO);
+            }
+            ImageFit::Sliced { slices, tile } => {
+                let resolve = |val: &Val, base: f32| match val {
+                    Val::Px(px) => *px,
+                    Val::Percent(percent) => base * percent / 100.0,
+                    _ => 0.0,
+                };
+                let scale_mode = if *tile {
+                    SliceScaleMode::Tile { stretch_value: 1.0 }
+                } else {
+                    SliceScaleMode::Stretch
+                };
+                let slicer = TextureSlicer {
+                    border: BorderRect {
+                        top: resolve(&slices[0], image_size.y),
+                        right: resolve(&slices[1], image_size.x),
+                        bottom: resolve(&slices[2], image_size.y),
+                        left: resolve(&slices[3], image_size.x),
+                    },
+                    center_scale_mode: SliceScaleMode::Stretch,
+                    sides_scale_mode: scale_mode,
+                    max_corner_scale: 1.0,
+                };
+                if !matches!(&image_node.image_mode, NodeImageMode::Sliced(current) if *current == slicer)
+                {
+                    image_node.image_mode = NodeImageMode::Sliced(slicer);
+                }
+            }
+            ImageFit::Natural | ImageFit::Repeat { .. } | ImageFit::Stretch => continue,
+        }
+        // เขียนเฉพาะเมื่อค่าเปลี่ยน เพื่อไม่ให้ layout / render ทำงานใหม่ทุก frame
+        if *node != updated_node {
+            *node = updated_node;
+        }
+        if image_node.rect != rect {
+            image_node.rect = rect;
+        }
+    }
+}
+
+fn place(node: &mut Node, size: Vec2, offset: Vec2) {
+    node.width = Val::Px(size.x);
+    node.height = Val::Px(size.y);
+    node.left = Val::Px(offset.x);
+    node.top = Val::Px(offset.y);
+}
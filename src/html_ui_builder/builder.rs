@@ -1,22 +1,63 @@
-use super::css::CssStyleSheet;
-use super::element::UIElement;
+use super::background::{BackgroundLayer, GradientMaterial, ImageFit, layer_node};
+use super::css::{CssStyleSheet, DEFAULT_FONT_SIZE};
+use super::element::{StateStyle, TextBlock, UIElement, fade};
+use super::font::{FontQuery, FontRegistry};
+use super::interaction::{InteractionStyles, NO_OUTLINE};
+use super::selector::ElementState;
+use super::text::{InheritedTextColor, TextEllipsis};
+use super::utils::root_font_size;
+use bevy::input_focus::InputFocus;
+use bevy::input_focus::tab_navigation::{TabGroup, TabIndex};
 use bevy::prelude::*;
+use bevy::ui::{FocusPolicy, RelativeCursorPosition};
 use lightningcss::stylesheet::{ParserOptions, StyleSheet};
 
 use scraper::{Html, Selector};
+use std::collections::HashMap;
 
-// Main UI Builder struct
+// Main UI Builder struct (เก็บเป็น Resource ได้เพื่อเรียก retheme ภายหลัง)
+#[derive(Default, Resource)]
 pub struct HtmlCssUIBuilder {
     stylesheet: Option<Box<CssStyleSheet>>,
-    owned_css: Option<String>,
+    // custom properties ที่ override ค่าใน stylesheet (ใช้เปลี่ยน theme ตอน runtime)
+    variables: HashMap<String, String>,
+    html_content: Option<String>,
+    root: Option<Entity>,
+    // entity ของแต่ละ UIElement ที่ spawn ไว้ ตามลำดับ pre-order (ใช้จับคู่ entity เก่ากับใหม่ตอน retheme)
+    spawned: Vec<Entity>,
+    // font files ที่ font-family / font-weight / font-style เลือกใช้
+    fonts: FontRegistry,
 }
 
 impl HtmlCssUIBuilder {
     pub fn new() -> Self {
-        Self {
-            stylesheet: None,
-            owned_css: None,
-        }
+        Self::default()
+    }
+
+    pub fn variables(&self) -> &HashMap<String, String> {
+        &self.variables
+    }
+
+    // เช่น set_variable("--primary", "#ff5722") แล้วเรียก retheme เพื่อสร้าง UI ใหม่
+    pub fn set_variable(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.variables.insert(name.into(), value.into());
+    }
+
+    pub fn remove_variable(&mut self, name: &str) {
+        self.variables.remove(name);
+    }
+
+    pub fn fonts(&self) -> &FontRegistry {
+        &self.fonts
+    }
+
+    // เช่น fonts_mut().register("Mono", "fonts/Mono.ttf", 400.0, false)
+    pub fn fonts_mut(&mut self) -> &mut FontRegistry {
+        &mut self.fonts
+    }
+
+    pub fn stylesheet(&self) -> Option<&CssStyleSheet> {
+        self.stylesheet.as_deref()
     }
 
     pub fn parse_and_build(
@@ -27,18 +68,74 @@ impl HtmlCssUIBuilder {
 
         // Extract และ parse CSS
         let css_content = self.extract_css(&document);
-        let stylesheet = if !css_content.is_empty() {
+        let mut stylesheet = if !css_content.is_empty() {
             Some(self.parse_css(&css_content)?)
         } else {
             None
         };
+        if let Some(stylesheet) = &mut stylesheet {
+            stylesheet.variables = self.variables.clone();
+            // @font-face แทนที่ font ที่ register ไว้ซึ่งมี family / weight / style เดียวกัน
+            for face in &stylesheet.font_faces {
+                self.fonts
+                    .register(&face.family, face.src.clone(), face.weight, face.italic);
+            }
+        }
 
         // Parse HTML elements
         let elements = self.parse_html_elements(&document, &stylesheet);
+        self.stylesheet = stylesheet;
+        self.html_content = Some(html_content.to_string());
 
         Ok(elements)
     }
 
+    // คำนวณ styles ใหม่ด้วยตัวแปรปัจจุบัน แล้วแทนที่ UI ที่ spawn ไว้ด้วย spawn_bevy_ui
+    // โดยย้าย ScrollPosition และ InputFocus ไปยัง entity ใหม่ของ element เดียวกัน
+    pub fn retheme(&mut self, commands: &mut Commands, asset_server: &Res<AssetServer>) {
+        let Some(html_content) = &self.html_content else {
+            return;
+        };
+        let document = Html::parse_document(html_content);
+        if let Some(stylesheet) = &mut self.stylesheet {
+            stylesheet.variables = self.variables.clone();
+        }
+        let elements = self.parse_html_elements(&document, &self.stylesheet);
+
+        let previous_root = self.root.take();
+        let previous = std::mem::take(&mut self.spawned);
+        self.spawn_bevy_ui(commands, asset_server, &elements);
+
+        // ทำงานหลัง spawn แต่ก่อน despawn จึงยังอ่านค่าจาก entity เก่าได้
+        let current = self.spawned.clone();
+        commands.queue(move |world: &mut World| {
+            // tree เปลี่ยนรูป (เช่น display เปลี่ยนตามตัวแปร) = จับคู่ตามลำดับไม่ได้
+            let same_tree = previous.len() == current.len();
+            if same_tree {
+                for (old, new) in previous.iter().zip(&current) {
+                    if let Some(position) = world.get::<ScrollPosition>(*old).cloned()
+                        && let Ok(mut entity) = world.get_entity_mut(*new)
+                    {
+                        entity.insert(position);
+                    }
+                }
+            }
+            if let Some(mut focus) = world.get_resource_mut::<InputFocus>()
+                && let Some(focused) = focus.get()
+                && let Some(index) = previous.iter().position(|entity| *entity == focused)
+            {
+                match current.get(index).filter(|_| same_tree) {
+                    Some(entity) => focus.set(*entity),
+                    None => focus.clear(),
+                }
+            }
+        });
+
+        if let Some(root) = previous_root {
+            commands.entity(root).despawn();
+        }
+    }
+
     fn extract_css(&self, document: &Html) -> String {
         let style_selector = Selector::parse("style").unwrap();
         let mut css_content = String::new();
@@ -73,12 +170,19 @@ impl HtmlCssUIBuilder {
         let body_selector = Selector::parse("body").unwrap();
 
         if let Some(body_element) = document.select(&body_selector).next() {
+            // rem ทั้ง document อ้างถึง font-size ของ root element ค่าเดียว
+            let root_font_size = stylesheet.as_ref().map_or(DEFAULT_FONT_SIZE, |stylesheet| {
+                root_font_size(&body_element, stylesheet)
+            });
             // สร้าง children ของ body แบบ recursive
             for child in body_element.children() {
                 if child.value().is_element() {
                     let child_ref = scraper::ElementRef::wrap(child).unwrap();
-                    let ui_element =
-                        UIElement::from_html_element_with_children(&child_ref, stylesheet);
+                    let ui_element = UIElement::from_html_element_with_children(
+                        &child_ref,
+                        stylesheet,
+                        root_font_size,
+                    );
                     elements.push(ui_element);
                 }
             }
@@ -88,30 +192,43 @@ impl HtmlCssUIBuilder {
     }
 
     pub fn spawn_bevy_ui(
-        &self,
+        &mut self,
         commands: &mut Commands,
         asset_server: &Res<AssetServer>,
         elements: &[UIElement],
     ) {
         // สร้าง root container
         let root = commands
-            .spawn(Node {
-                width: Val::Percent(100.0),
-                height: Val::Percent(100.0),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            })
+            .spawn((
+                TabGroup::new(0),
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+            ))
             .id();
+        self.root = Some(root);
 
         // สร้าง UI hierarchy แบบ recursive
+        let mut spawned = Vec::new();
         for element in elements {
             if element.tag == "div" && element.classes.contains(&"container".to_string()) {
-                let entity = self.spawn_element_recursive(commands, asset_server, element);
+                let entity = self.spawn_element_recursive(
+                    commands,
+                    asset_server,
+                    element,
+                    1.0,
+                    None,
+                    &mut spawned,
+                );
                 commands.entity(root).add_child(entity);
                 break; // ใช้แค่ container หลัก
             }
         }
+        self.spawned = spawned;
     }
 
     fn spawn_element_recursive(
@@ -119,58 +236,265 @@ impl HtmlCssUIBuilder {
         commands: &mut Commands,
         asset_server: &Res<AssetServer>,
         element: &UIElement,
+        // opacity ของ ancestors (opacity มีผลกับทั้ง subtree)
+        inherited_opacity: f32,
+        // color ของ parent (None = root)
+        parent_text_color: Option<Color>,
+        spawned: &mut Vec<Entity>,
     ) -> Entity {
+        let style = StateStyle {
+            state: ElementState::default(),
+            computed_style: element.computed_style.clone(),
+            calc_styles: element.calc_styles.clone(),
+            background_color: element.background_color,
+            border_color: element.border_color,
+            box_shadow: element.box_shadow.clone(),
+            outline: element.outline,
+            text_color: element.text_color,
+            opacity: element.opacity,
+        }
+        .with_opacity(inherited_opacity);
+        let opacity = inherited_opacity * element.opacity;
+
         let mut entity_commands = commands.spawn((
             element.computed_style.clone(),
-            element.background_color,
+            style.background_color,
+            style.border_color,
             element.border_radius,
+            element.visibility,
         ));
 
-        // เพิ่ม text ถ้าเป็น text elements เช่น h1, p, button
-        if !element.text.is_empty()
-            && matches!(
-                element.tag.as_str(),
-                "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "p" | "span" | "button"
-            )
-        {
+        // background-image / border-image เป็น children ลำดับแรก จึงวาดอยู่ใต้ text และ children อื่น
+        if !element.background_layers.is_empty() {
             entity_commands.with_children(|parent| {
-                // พยายามโหลด font file ก่อน
-                let font_path = "fonts/Prompt-Medium.ttf";
-                let font_handle = asset_server.load(font_path);
-
-                // ตรวจสอบว่า font โหลดได้หรือไม่ ถ้าไม่ได้ใช้ default
-                let final_font_handle =
-                    if std::path::Path::new(&format!("assets/{}", font_path)).exists() {
-                        font_handle
-                    } else {
-                        Handle::default()
+                for layer in &element.background_layers {
+                    let mut layer_commands = match layer {
+                        BackgroundLayer::Image { path, fit } => {
+                            let (node, image_node) =
+                                fit.layer_bundle(asset_server.load(path.clone()));
+                            parent.spawn((
+                                node,
+                                image_node.with_color(fade(Color::WHITE, opacity)),
+                                fit.clone(),
+                            ))
+                        }
+                        BackgroundLayer::Gradient(gradient) => {
+                            let mut gradient = gradient.clone();
+                            for (color, _) in &mut gradient.stops {
+                                *color = fade(*color, opacity);
+                            }
+                            parent.spawn((
+                                layer_node(),
+                                element.border_radius,
+                                MaterialNode(asset_server.add(GradientMaterial::default())),
+                                gradient,
+                            ))
+                        }
                     };
-
-                parent.spawn((
-                    Text::new(element.text.clone()),
-                    TextFont {
-                        font: final_font_handle,
-                        font_size: element.font_size,
-                        ..default()
-                    },
-                    TextColor(element.text_color),
-                ));
+                    // ไม่รับ hover / click แทน element
+                    layer_commands.insert((FocusPolicy::Pass, Pickable::IGNORE));
+                }
             });
         }
 
+        // <img src> แบบ object-fit: fill ใส่ ImageNode ให้ element เองเพื่อให้ขนาดตามภาพได้
+        if let Some(src) = element
+            .attributes
+            .get("src")
+            .filter(|_| element.tag == "img")
+        {
+            let image = asset_server.load(src.trim_start_matches("./").to_string());
+            let tint = fade(Color::WHITE, opacity);
+            if element.object_fit == ImageFit::Stretch {
+                entity_commands.insert(ImageNode::new(image).with_color(tint));
+            } else {
+                let (node, image_node) = element.object_fit.layer_bundle(image);
+                entity_commands.with_children(|parent| {
+                    parent.spawn((
+                        node,
+                        image_node.with_color(tint),
+                        element.object_fit.clone(),
+                        FocusPolicy::Pass,
+                        Pickable::IGNORE,
+                    ));
+                });
+            }
+        }
+
+        if let Some(z_index) = element.z_index {
+            entity_commands.insert(z_index);
+        }
+        if let Some(global_z_index) = element.global_z_index {
+            entity_commands.insert(global_z_index);
+        }
+
         // เพิ่ม interaction สำหรับ button
         if element.classes.contains(&"button".to_string()) || element.tag == "button" {
             entity_commands.insert(Interaction::default());
         }
 
+        // element ที่มี :hover / :active / :focus styles
+        if !element.state_styles.is_empty() {
+            let states = element
+                .state_styles
+                .iter()
+                .map(|state_style| state_style.clone().with_opacity(inherited_opacity))
+                .collect();
+            entity_commands.insert((
+                Interaction::default(),
+                InteractionStyles::new(style.clone(), states),
+            ));
+        }
+
+        // BoxShadow ว่างไม่วาดอะไร แต่ต้องมี component ไว้ให้ state styles เปลี่ยนได้
+        if !element.box_shadow.is_empty()
+            || element
+                .state_styles
+                .iter()
+                .any(|style| !style.box_shadow.is_empty())
+        {
+            entity_commands.insert(style.box_shadow.clone());
+        }
+
+        // outline (เช่น focus ring จาก :focus-visible) — ความกว้าง 0 เมื่อไม่มี outline
+        if element.outline.is_some()
+            || element
+                .state_styles
+                .iter()
+                .any(|style| style.outline.is_some())
+        {
+            entity_commands.insert(style.outline.unwrap_or(NO_OUTLINE));
+        }
+
+        // scroll container ต้องรู้ว่า cursor อยู่เหนือมันหรือไม่ (ใช้โดย scroll_on_wheel / scroll_on_drag)
+        let scrolls = |node: &Node| {
+            node.overflow.x == OverflowAxis::Scroll || node.overflow.y == OverflowAxis::Scroll
+        };
+        if scrolls(&element.computed_style)
+            || element
+                .state_styles
+                .iter()
+                .any(|style| scrolls(&style.computed_style))
+        {
+            entity_commands.insert(RelativeCursorPosition::default());
+        }
+
+        // color เดียวกับ parent = สืบทอดมา จึงเปลี่ยนตาม :hover ของ ancestor ได้
+        if element.opacity == 1.0 && parent_text_color == Some(element.text_color) {
+            entity_commands.insert(InheritedTextColor);
+        }
+
+        // element ที่ focus ได้ด้วย Tab (button, a, [tabindex]) — Interaction ให้คลิกแล้วได้ focus ด้วย
+        if let Some(tab_index) = element.tab_index() {
+            entity_commands.insert((TabIndex(tab_index), Interaction::default()));
+        }
+
+        // calc() ที่ต้องรู้ขนาดของ parent / viewport (รวมถึงของ state styles)
+        if !element.calc_styles.is_empty()
+            || element
+                .state_styles
+                .iter()
+                .any(|style| !style.calc_styles.is_empty())
+        {
+            entity_commands.insert(element.calc_styles.clone());
+        }
+
         let entity_id = entity_commands.id();
+        spawned.push(entity_id);
 
-        // สร้าง children แบบ recursive
-        for child_element in &element.children {
-            let child_entity = self.spawn_element_recursive(commands, asset_server, child_element);
-            commands.entity(entity_id).add_child(child_entity);
+        // สร้าง children แบบ recursive โดยแทรก text blocks ตามตำแหน่งใน HTML
+        for position in 0..=element.children.len() {
+            for block in &element.text_blocks {
+                if block.position == position {
+                    let text_entity = self.spawn_text_block(
+                        commands,
+                        asset_server,
+                        element,
+                        block,
+                        style.text_color,
+                        opacity,
+                    );
+                    commands.entity(entity_id).add_child(text_entity);
+                }
+            }
+            if let Some(child_element) = element.children.get(position) {
+                let child_entity = self.spawn_element_recursive(
+                    commands,
+                    asset_server,
+                    child_element,
+                    opacity,
+                    Some(element.text_color),
+                    spawned,
+                );
+                commands.entity(entity_id).add_child(child_entity);
+            }
         }
 
         entity_id
     }
+
+    // inline content เป็น Text ว่างที่มี TextSpan ต่อ run (สี / ขนาด / font ของแต่ละ run)
+    fn spawn_text_block(
+        &self,
+        commands: &mut Commands,
+        asset_server: &Res<AssetServer>,
+        element: &UIElement,
+        block: &TextBlock,
+        // สีของ element ที่คูณ opacity แล้ว
+        text_color: Color,
+        opacity: f32,
+    ) -> Entity {
+        // text-align จัดภายในกล่องของ text จึงต้องกว้างเท่า content box ของ element
+        let mut node = Node::default();
+        if element.text_layout.justify != JustifyText::Left {
+            node.width = Val::Percent(100.0);
+        }
+        // ellipsis: ไม่ให้ text หดตาม parent เพื่อวัดความยาวจริงได้
+        if element.text_ellipsis {
+            node.flex_shrink = 0.0;
+        }
+        let mut text_commands = commands.spawn((
+            node,
+            Text::default(),
+            TextFont {
+                font: self.load_font(asset_server, &element.font),
+                font_size: element.font_size,
+                line_height: element.line_height,
+                ..default()
+            },
+            TextColor(text_color),
+            element.text_layout,
+        ));
+        text_commands.with_children(|parent| {
+            for run in &block.runs {
+                let mut span_commands = parent.spawn((
+                    TextSpan::new(run.text.clone()),
+                    TextFont {
+                        font: self.load_font(asset_server, &run.font),
+                        font_size: run.font_size,
+                        line_height: run.line_height,
+                        ..default()
+                    },
+                    TextColor(run.color.map_or(text_color, |color| fade(color, opacity))),
+                ));
+                if run.color.is_none() {
+                    span_commands.insert(InheritedTextColor);
+                }
+            }
+        });
+        if element.text_ellipsis {
+            let runs = block.runs.iter().map(|run| run.text.clone()).collect();
+            text_commands.insert(TextEllipsis::new(runs));
+        }
+        text_commands.id()
+    }
+
+    // font file จาก registry ถ้าไม่มีไฟล์ใช้ font เริ่มต้นของ Bevy
+    fn load_font(&self, asset_server: &Res<AssetServer>, font: &FontQuery) -> Handle<Font> {
+        self.fonts
+            .resolve(font)
+            .filter(|face| std::path::Path::new(&format!("assets/{}", face.path)).exists())
+            .map(|face| asset_server.load(face.path.clone()))
+            .unwrap_or_default()
+    }
 }
@@ -0,0 +1,116 @@
+use super::css::CalcLength;
+use bevy::prelude::*;
+
+// Node fields ที่รับค่าจาก calc() ได้
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalcField {
+    Width,
+    Height,
+    MinWidth,
+    MinHeight,
+    MaxWidth,
+    MaxHeight,
+    FlexBasis,
+    Top,
+    Right,
+    Bottom,
+    Left,
+    PaddingTop,
+    PaddingRight,
+    PaddingBottom,
+    PaddingLeft,
+    MarginTop,
+    MarginRight,
+    MarginBottom,
+    MarginLeft,
+    RowGap,
+    ColumnGap,
+}
+
+impl CalcField {
+    pub fn val_mut(self, node: &mut Node) -> &mut Val {
+        match self {
+            CalcField::Width => &mut node.width,
+            CalcField::Height => &mut node.height,
+            CalcField::MinWidth => &mut node.min_width,
+            CalcField::MinHeight => &mut node.min_height,
+            CalcField::MaxWidth => &mut node.max_width,
+            CalcField::MaxHeight => &mut node.max_height,
+            CalcField::FlexBasis => &mut node.flex_basis,
+            CalcField::Top => &mut node.top,
+            CalcField::Right => &mut node.right,
+            CalcField::Bottom => &mut node.bottom,
+            CalcField::Left => &mut node.left,
+            CalcField::PaddingTop => &mut node.padding.top,
+            CalcField::PaddingRight => &mut node.padding.right,
+            CalcField::PaddingBottom => &mut node.padding.bottom,
+            CalcField::PaddingLeft => &mut node.padding.left,
+            CalcField::MarginTop => &mut node.margin.top,
+            CalcField::MarginRight => &mut node.margin.right,
+            CalcField::MarginBottom => &mut node.margin.bottom,
+            CalcField::MarginLeft => &mut node.margin.left,
+            CalcField::RowGap => &mut node.row_gap,
+            CalcField::ColumnGap => &mut node.column_gap,
+        }
+    }
+
+    // % ของ height / top / bottom / row-gap เทียบกับความสูง นอกนั้นเทียบกับความกว้าง
+    // (padding / margin ทุกด้านเทียบกับความกว้างของ parent เหมือน browser)
+    fn percent_base(self, size: Vec2) -> f32 {
+        match self {
+            CalcField::Height
+            | CalcField::MinHeight
+            | CalcField::MaxHeight
+            | CalcField::Top
+            | CalcField::Bottom
+            | CalcField::RowGap => size.y,
+            _ => size.x,
+        }
+    }
+}
+
+// calc() ที่ต้องใช้ขนาดของ parent หรือ viewport — คำนวณใหม่ทุก frame โดย apply_calc_styles
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct CalcStyles(pub Vec<(CalcField, CalcLength)>);
+
+impl CalcStyles {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+pub fn apply_calc_styles(
+    mut nodes: Query<(
+        &CalcStyles,
+        &mut Node,
+        &ComputedNodeTarget,
+        Option<&ChildOf>,
+    )>,
+    computed_nodes: Query<&ComputedNode>,
+) {
+    for (calc_styles, mut node, target, child_of) in &mut nodes {
+        let viewport = target.logical_size();
+        // % เทียบกับ content box ของ parent, root node เทียบกับ viewport
+        let parent_size = child_of
+            .and_then(|child_of| computed_nodes.get(child_of.parent()).ok())
+            .map(|parent| {
+                let (padding, border) = (parent.padding(), parent.border());
+                let insets = Vec2::new(
+                    padding.left + padding.right + border.left + border.right,
+                    padding.top + padding.bottom + border.top + border.bottom,
+                );
+                (parent.size() - insets) * parent.inverse_scale_factor()
+            })
+            .unwrap_or(viewport);
+
+        let mut updated = node.clone();
+        for (field, calc) in &calc_styles.0 {
+            let value = calc.evaluate(field.percent_base(parent_size), viewport);
+            *field.val_mut(&mut updated) = Val::Px(value);
+        }
+        // เขียนเฉพาะเมื่อค่าเปลี่ยน เพื่อไม่ให้ layout ทำงานใหม่ทุก frame
+        if *node != updated {
+            *node = updated;
+        }
+    }
+}
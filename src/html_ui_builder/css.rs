@@ -1,225 +1,1802 @@
-use bevy::ui::Val;
-use lightningcss::properties::Property;
-use lightningcss::properties::font::FontSize;
-use lightningcss::properties::size::Size;
+use super::selector::{Selector, parse_selector_list};
+use bevy::log::warn;
+use bevy::math::Vec2;
+use bevy::ui::{
+    GridTrack, GridTrackRepetition, MaxTrackSizingFunction, MinTrackSizingFunction,
+    RepeatedGridTrack, Val,
+};
+use lightningcss::properties::align::GapValue;
+use lightningcss::properties::background::{
+    BackgroundRepeat, BackgroundRepeatKeyword, BackgroundSize,
+};
+use lightningcss::properties::border::{BorderSideWidth, GenericBorder, LineStyle};
+use lightningcss::properties::border_image::BorderImageSlice;
+use lightningcss::properties::custom::CustomPropertyName;
+use lightningcss::properties::font::{
+    AbsoluteFontSize, AbsoluteFontWeight, FontFamily, FontSize, FontWeight, LineHeight,
+    RelativeFontSize,
+};
+use lightningcss::properties::grid::{
+    GridLine, RepeatCount, TrackBreadth, TrackListItem, TrackSize, TrackSizeList, TrackSizing,
+};
+use lightningcss::properties::position::ZIndex;
+use lightningcss::properties::size::{MaxSize, Size};
+use lightningcss::properties::text::Spacing;
+use lightningcss::properties::{CSSWideKeyword, Property, PropertyId};
 use lightningcss::rules::CssRule;
-use lightningcss::stylesheet::StyleSheet;
+use lightningcss::rules::font_face::{
+    FontFaceProperty, FontStyle as FontFaceStyle, Source, UrlSource,
+};
+use lightningcss::stylesheet::{ParserOptions, PrinterOptions, StyleSheet};
+use lightningcss::traits::{Parse, ToCss};
+use lightningcss::values::calc::{Calc, MathFunction};
 use lightningcss::values::color::CssColor;
-use lightningcss::values::percentage::DimensionPercentage;
+use lightningcss::values::gradient::{
+    Circle, Ellipse, EndingShape, Gradient, GradientItem, LineDirection, LinearGradient,
+    RadialGradient, ShapeExtent,
+};
+use lightningcss::values::image::Image;
+use lightningcss::values::length::{Length, LengthPercentage, LengthPercentageOrAuto, LengthValue};
+use lightningcss::values::percentage::{DimensionPercentage, NumberOrPercentage};
+use lightningcss::values::position::{
+    HorizontalPositionKeyword, PositionComponent, VerticalPositionKeyword,
+};
 use std::collections::HashMap;
 use std::default::Default;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+// Properties whose computed value flows from parent to children
+pub const INHERITED_PROPERTIES: &[&str] = &[
+    "color",
+    "font-size",
+    "font-family",
+    "font-weight",
+    "font-style",
+    "line-height",
+    "text-align",
+    "letter-spacing",
+    "white-space",
+    "visibility",
+];
+
+pub fn is_inherited_property(name: &str) -> bool {
+    INHERITED_PROPERTIES.contains(&name)
+}
 
 #[derive(Debug, Default)]
 pub struct CssStyleSheet {
     pub rules: Vec<CssRule_>,
+    // @font-face ตามลำดับใน stylesheet
+    pub font_faces: Vec<CssFontFace>,
+    // custom properties ที่ override จาก Rust (เหมือน inline style บน root element)
+    pub variables: HashMap<String, String>,
+}
+
+// @font-face ที่ใช้ได้ (src เป็น path เทียบกับโฟลเดอร์ assets)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssFontFace {
+    pub family: String,
+    pub src: String,
+    pub weight: f32,
+    pub italic: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct CssRule_ {
     pub selector: String,
+    pub selectors: Vec<Selector>,
     pub properties: HashMap<String, CssPropertyValue>,
+    // declarations marked `!important`
+    pub important_properties: HashMap<String, CssPropertyValue>,
 }
 
 #[derive(Debug, Clone)]
 pub enum CssPropertyValue {
     Color(CssColor),
     Size(f32),
+    Length(CssLength),
+    Number(f32),
+    Integer(i32),
     String(String),
+    // inherit / initial / unset / revert
+    Keyword(CSSWideKeyword),
+    // ค่าดิบของ custom property (--name)
+    Custom(String),
+    // ค่าที่มี var() — แทนค่าและ parse ใหม่ตอน compute styles
+    // (shorthand เก็บไว้ที่ทุก longhand เพื่อให้แต่ละ longhand cascade ตามตำแหน่งของ declaration)
+    Var {
+        property: String,
+        value: String,
+    },
     Rect {
-        top: Val,
-        right: Val,
-        bottom: Val,
-        left: Val,
+        top: CssLength,
+        right: CssLength,
+        bottom: CssLength,
+        left: CssLength,
     },
     Corner {
-        top_left: Val,
-        top_right: Val,
-        bottom_right: Val,
-        bottom_left: Val,
+        top_left: CssLength,
+        top_right: CssLength,
+        bottom_right: CssLength,
+        bottom_left: CssLength,
     },
+    // grid-template-rows / grid-template-columns
+    GridTemplate(Vec<RepeatedGridTrack>),
+    // grid-auto-rows / grid-auto-columns
+    GridTracks(Vec<GridTrack>),
+    // grid-row-start / grid-row-end / grid-column-start / grid-column-end
+    GridLine(GridLineValue),
+    // box-shadow (ว่าง = none)
+    Shadows(Vec<CssShadow>),
+    // background-image / border-image-source (หนึ่งค่าต่อ layer, layer แรกอยู่บนสุด)
+    Images(Vec<CssImage>),
+    // background-size ต่อ layer
+    BackgroundSizes(Vec<CssBackgroundSize>),
+    // background-repeat ต่อ layer เป็น (repeat แกน x, repeat แกน y)
+    BackgroundRepeats(Vec<(bool, bool)>),
+}
+
+// Initial value of font-size (and the root font size when `html` doesn't set one)
+pub const DEFAULT_FONT_SIZE: f32 = 16.0;
+
+// Length ที่ยังไม่ได้ resolve หน่วยที่ขึ้นกับ font (em, rem) — resolve ตอน compute styles
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssLength {
+    // px, %, auto และ viewport units (vw, vh, vmin, vmax)
+    Val(Val),
+    Em(f32),
+    Rem(f32),
+    // calc() / min() / max() / clamp()
+    Calc(Box<CalcLength>),
+}
+
+impl CssLength {
+    // calc() ที่รวมเป็น Val เดียวไม่ได้ (เช่น 100% - 20px) จะได้ Val::Auto
+    // ค่าจริงคำนวณตอน runtime โดย CalcStyles
+    pub fn resolve(&self, font_size: f32, root_font_size: f32) -> Val {
+        match self.resolve_font_relative(font_size, root_font_size) {
+            CssLength::Val(val) => val,
+            _ => Val::Auto,
+        }
+    }
+
+    // แทน em / rem ด้วย px โดยเก็บ calc() ที่ยังต้องใช้ขนาดของ parent หรือ viewport ไว้
+    pub fn resolve_font_relative(&self, font_size: f32, root_font_size: f32) -> CssLength {
+        match self {
+            CssLength::Val(val) => CssLength::Val(*val),
+            CssLength::Em(value) => CssLength::Val(Val::Px(value * font_size)),
+            CssLength::Rem(value) => CssLength::Val(Val::Px(value * root_font_size)),
+            CssLength::Calc(calc) => {
+                let calc = calc.resolve_font_relative(font_size, root_font_size);
+                match calc.to_val() {
+                    Some(val) => CssLength::Val(val),
+                    None => CssLength::Calc(Box::new(calc)),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalcFunction {
+    Min,
+    Max,
+    Clamp,
+    Abs,
+}
+
+impl CalcFunction {
+    fn apply(&self, args: &[f32]) -> f32 {
+        match (self, args) {
+            (CalcFunction::Min, _) => args.iter().copied().fold(f32::INFINITY, f32::min),
+            (CalcFunction::Max, _) => args.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+            // clamp(MIN, VAL, MAX) = max(MIN, min(VAL, MAX))
+            (CalcFunction::Clamp, [min, value, max]) => value.min(*max).max(*min),
+            (CalcFunction::Abs, [value]) => value.abs(),
+            _ => 0.0,
+        }
+    }
+}
+
+// Expression ของ CSS math functions
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalcLength {
+    Length(CssLength),
+    Number(f32),
+    Sum(Box<CalcLength>, Box<CalcLength>),
+    Product(f32, Box<CalcLength>),
+    Function(CalcFunction, Vec<CalcLength>),
+}
+
+// สัมประสิทธิ์ของแต่ละหน่วย: px, %, vw, vh, vmin, vmax
+type LinearLength = [f32; 6];
+
+impl CalcLength {
+    fn resolve_font_relative(&self, font_size: f32, root_font_size: f32) -> CalcLength {
+        let resolve =
+            |calc: &CalcLength| Box::new(calc.resolve_font_relative(font_size, root_font_size));
+        match self {
+            CalcLength::Length(length) => {
+                CalcLength::Length(length.resolve_font_relative(font_size, root_font_size))
+            }
+            CalcLength::Number(number) => CalcLength::Number(*number),
+            CalcLength::Sum(a, b) => CalcLength::Sum(resolve(a), resolve(b)),
+            CalcLength::Product(factor, calc) => CalcLength::Product(*factor, resolve(calc)),
+            CalcLength::Function(function, args) => CalcLength::Function(
+                *function,
+                args.iter()
+                    .map(|arg| arg.resolve_font_relative(font_size, root_font_size))
+                    .collect(),
+            ),
+        }
+    }
+
+    // รวมเป็น Val เดียวได้ถ้าทุกพจน์ใช้หน่วยเดียวกัน เช่น calc(10px + 2px) หรือ min(50vw, 20vw)
+    pub fn to_val(&self) -> Option<Val> {
+        let linear = self.linear()?;
+        let mut units = (0..linear.len()).filter(|unit| linear[*unit] != 0.0);
+        let val = match (units.next(), units.next()) {
+            (None, _) => Val::Px(0.0),
+            (Some(unit), None) => {
+                let value = linear[unit];
+                match unit {
+                    0 => Val::Px(value),
+                    1 => Val::Percent(value),
+                    2 => Val::Vw(value),
+                    3 => Val::Vh(value),
+                    4 => Val::VMin(value),
+                    _ => Val::VMax(value),
+                }
+            }
+            _ => return None,
+        };
+        Some(val)
+    }
+
+    fn linear(&self) -> Option<LinearLength> {
+        let mut linear = [0.0; 6];
+        match self {
+            CalcLength::Length(CssLength::Val(val)) => {
+                let (unit, value) = match *val {
+                    Val::Px(value) => (0, value),
+                    Val::Percent(value) => (1, value),
+                    Val::Vw(value) => (2, value),
+                    Val::Vh(value) => (3, value),
+                    Val::VMin(value) => (4, value),
+                    Val::VMax(value) => (5, value),
+                    Val::Auto => return None,
+                };
+                linear[unit] = value;
+            }
+            CalcLength::Length(CssLength::Calc(calc)) => return calc.linear(),
+            // em / rem ต้อง resolve ก่อน
+            CalcLength::Length(_) | CalcLength::Number(_) => return None,
+            CalcLength::Sum(a, b) => {
+                let (a, b) = (a.linear()?, b.linear()?);
+                for unit in 0..linear.len() {
+                    linear[unit] = a[unit] + b[unit];
+                }
+            }
+            CalcLength::Product(factor, calc) => {
+                linear = calc.linear()?.map(|value| value * factor);
+            }
+            // min / max / clamp เทียบค่ากันได้เฉพาะเมื่อทุก argument ใช้หน่วยเดียวกัน
+            CalcLength::Function(function, args) => {
+                let args = args
+                    .iter()
+                    .map(CalcLength::linear)
+                    .collect::<Option<Vec<_>>>()?;
+                let mut units =
+                    (0..linear.len()).filter(|unit| args.iter().any(|arg| arg[*unit] != 0.0));
+                if let Some(unit) = units.next() {
+                    if units.next().is_some() {
+                        return None;
+                    }
+                    let values: Vec<f32> = args.iter().map(|arg| arg[unit]).collect();
+                    linear[unit] = function.apply(&values);
+                }
+            }
+        }
+        Some(linear)
+    }
+
+    // ค่าเป็น px โดย % เทียบกับ `percent_base` และ viewport units เทียบกับ `viewport`
+    pub fn evaluate(&self, percent_base: f32, viewport: Vec2) -> f32 {
+        match self {
+            CalcLength::Length(CssLength::Calc(calc)) => calc.evaluate(percent_base, viewport),
+            CalcLength::Length(length) => length
+                .resolve(DEFAULT_FONT_SIZE, DEFAULT_FONT_SIZE)
+                .resolve(percent_base, viewport)
+                .unwrap_or(0.0),
+            CalcLength::Number(number) => *number,
+            CalcLength::Sum(a, b) => {
+                a.evaluate(percent_base, viewport) + b.evaluate(percent_base, viewport)
+            }
+            CalcLength::Product(factor, calc) => factor * calc.evaluate(percent_base, viewport),
+            CalcLength::Function(function, args) => {
+                let values: Vec<f32> = args
+                    .iter()
+                    .map(|arg| arg.evaluate(percent_base, viewport))
+                    .collect();
+                function.apply(&values)
+            }
+        }
+    }
+}
+
+impl From<Val> for CssLength {
+    fn from(val: Val) -> Self {
+        CssLength::Val(val)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssShadow {
+    pub color: CssColor,
+    pub x_offset: CssLength,
+    pub y_offset: CssLength,
+    pub blur: CssLength,
+    pub spread: CssLength,
+}
+
+// background-image / border-image-source
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssImage {
+    None,
+    // path เทียบกับโฟลเดอร์ assets (โหลดผ่าน AssetServer)
+    Url(String),
+    Gradient(CssGradient),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssGradient {
+    pub shape: GradientShape,
+    pub repeating: bool,
+    pub stops: Vec<CssColorStop>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum GradientShape {
+    // มุมแบบ CSS เป็น radians: 0 = to top แล้วหมุนตามเข็มนาฬิกา
+    Linear(f32),
+    // to top right ฯลฯ — มุมจริงขึ้นกับสัดส่วนของกล่อง (x: -1 ซ้าย / 1 ขวา, y: -1 บน / 1 ล่าง)
+    LinearCorner(Vec2),
+    // center เป็นสัดส่วนของกล่อง (0.5, 0.5 = ตรงกลาง)
+    Radial {
+        circle: bool,
+        extent: RadialExtent,
+        center: Vec2,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RadialExtent {
+    ClosestSide,
+    FarthestSide,
+    ClosestCorner,
+    FarthestCorner,
+    // รัศมีแกน x, y (circle ใช้ค่า x ทั้งสองแกน)
+    Radius(CssLength, CssLength),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssColorStop {
+    pub color: CssColor,
+    pub position: Option<CssLength>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CssBackgroundSize {
+    Cover,
+    Contain,
+    // auto = Val::Auto
+    Explicit(CssLength, CssLength),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GridLineValue {
+    Auto,
+    Line(i16),
+    Span(u16),
 }
 
 impl CssStyleSheet {
     pub fn from_lightningcss(stylesheet: StyleSheet) -> Self {
         let mut rules = Vec::new();
 
+        let mut font_faces = Vec::new();
+
         for rule in &stylesheet.rules.0 {
+            if let CssRule::FontFace(font_face) = rule {
+                font_faces.extend(css_font_face(&font_face.properties));
+            }
             if let CssRule::Style(style_rule) = rule {
                 let selector_str = style_rule.selectors.to_string();
                 let mut properties = HashMap::new();
+                let mut important_properties = HashMap::new();
 
                 for declaration in &style_rule.declarations.declarations {
-                    match declaration {
-                        Property::BackgroundColor(color) => {
-                            properties.insert(
-                                "background-color".to_string(),
-                                CssPropertyValue::Color(color.clone()),
-                            );
-                        }
-                        Property::Color(color) => {
-                            properties.insert(
-                                "color".to_string(),
-                                CssPropertyValue::Color(color.clone()),
-                            );
-                        }
-                        Property::FontSize(size) => {
-                            let font_size = extract_font_size_value(size);
-                            properties
-                                .insert("font-size".to_string(), CssPropertyValue::Size(font_size));
-                        }
-                        Property::Width(width) => {
-                            let value = extract_size_value(width);
-                            properties.insert("width".to_string(), CssPropertyValue::Size(value));
-                        }
-                        Property::Height(height) => {
-                            let value = extract_size_value(height);
-                            properties.insert("height".to_string(), CssPropertyValue::Size(value));
-                        }
-                        Property::Padding(padding) => {
-                            let (top, right, bottom, left) =
-                                extract_padding_values(&Property::Padding(padding.clone()));
-                            properties.insert(
-                                "padding".to_string(),
-                                CssPropertyValue::Rect {
-                                    top,
-                                    right,
-                                    bottom,
-                                    left,
-                                },
-                            );
-                        }
-                        Property::Margin(margin) => {
-                            let (top, right, bottom, left) =
-                                extract_margin_values(&Property::Margin(margin.clone()));
-                            properties.insert(
-                                "margin".to_string(),
-                                CssPropertyValue::Rect {
-                                    top,
-                                    right,
-                                    bottom,
-                                    left,
-                                },
-                            );
-                        }
-                        Property::BorderRadius(border_radius, _) => {
-                            let (top_left, top_right, bottom_right, bottom_left) =
-                                extract_corner_values(&border_radius);
-                            properties.insert(
-                                "border-radius".to_string(),
-                                CssPropertyValue::Corner {
-                                    top_left,
-                                    top_right,
-                                    bottom_right,
-                                    bottom_left,
-                                },
-                            );
-                        }
-                        _ => {} // Handle other properties as needed
-                    }
+                    insert_declaration(&mut properties, declaration);
+                }
+                for declaration in &style_rule.declarations.important_declarations {
+                    insert_declaration(&mut important_properties, declaration);
                 }
 
                 rules.push(CssRule_ {
+                    selectors: parse_selector_list(&selector_str),
                     selector: selector_str,
                     properties,
+                    important_properties,
                 });
             }
         }
 
-        CssStyleSheet { rules }
+        CssStyleSheet {
+            rules,
+            font_faces,
+            variables: HashMap::new(),
+        }
     }
 }
 
-// Helper functions
-fn extract_font_size_value(size: &lightningcss::properties::font::FontSize) -> f32 {
-    match size {
-        FontSize::Length(length) => match length {
-            DimensionPercentage::Dimension(len) => len.to_px().unwrap_or(0.0),
-            DimensionPercentage::Percentage(pct) => pct.0,
-            DimensionPercentage::Calc(_) => 0.0,
-        },
-        _ => 16.0,
+pub const BORDER_SIDES: [&str; 4] = ["top", "right", "bottom", "left"];
+
+// Parse ค่าของ property ที่แทน var() แล้ว (shorthand อาจได้หลาย properties)
+pub fn parse_declaration(name: &str, value: &str) -> HashMap<String, CssPropertyValue> {
+    let mut properties = HashMap::new();
+    if let Ok(property) =
+        Property::parse_string(PropertyId::from(name), value, ParserOptions::default())
+    {
+        insert_declaration(&mut properties, &property);
     }
+    properties
 }
 
-fn extract_size_value(size: &lightningcss::properties::size::Size) -> f32 {
-    match size {
-        Size::LengthPercentage(dim_pct) => {
-            match dim_pct {
-                DimensionPercentage::Dimension(len) => {
-                    // แปลงเป็น px
-                    len.to_px().unwrap_or(0.0)
+// ชื่อที่ property เก็บค่าไว้ได้: ตัวมันเองและ longhands ทุกชั้นถ้าเป็น shorthand
+// (margin → margin-top, ...; border → border-width → border-top-width, ...)
+pub fn property_names(name: &str) -> Vec<String> {
+    let mut names = vec![name.to_string()];
+    for longhand in PropertyId::from(name).longhands().into_iter().flatten() {
+        for name in property_names(longhand.name()) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+    names
+}
+
+// แทน var(--name, fallback) ด้วยค่าจาก `lookup`
+// None = invalid at computed-value time (ไม่มีตัวแปรและไม่มี fallback)
+pub fn substitute_var(value: &str, lookup: &dyn Fn(&str) -> Option<String>) -> Option<String> {
+    let mut result = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("var(") {
+        result.push_str(&rest[..start]);
+        let arguments = &rest[start + "var(".len()..];
+        let end = closing_paren(arguments)?;
+        let (name, fallback) = match top_level_comma(&arguments[..end]) {
+            Some(comma) => (&arguments[..comma], Some(&arguments[comma + 1..end])),
+            None => (&arguments[..end], None),
+        };
+        let substituted = match lookup(name.trim()) {
+            Some(value) => value,
+            None => substitute_var(fallback?.trim(), lookup)?,
+        };
+        result.push_str(&substituted);
+        rest = &arguments[end + 1..];
+    }
+    result.push_str(rest);
+    Some(result)
+}
+
+// index ของ `)` ที่ปิดวงเล็บที่เปิดไว้ก่อน `text`
+fn closing_paren(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (index, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some(index),
+            ')' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn top_level_comma(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (index, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+// Helper functions
+fn insert_declaration(
+    properties: &mut HashMap<String, CssPropertyValue>,
+    declaration: &Property<'_>,
+) {
+    match declaration {
+        Property::BackgroundColor(color) => {
+            properties.insert(
+                "background-color".to_string(),
+                CssPropertyValue::Color(color.clone()),
+            );
+        }
+        Property::Background(backgrounds) => {
+            // สีใช้ได้เฉพาะใน layer สุดท้าย
+            if let Some(background) = backgrounds.last() {
+                properties.insert(
+                    "background-color".to_string(),
+                    CssPropertyValue::Color(background.color.clone()),
+                );
+            }
+            properties.insert(
+                "background-image".to_string(),
+                CssPropertyValue::Images(backgrounds.iter().map(|b| css_image(&b.image)).collect()),
+            );
+            properties.insert(
+                "background-size".to_string(),
+                CssPropertyValue::BackgroundSizes(
+                    backgrounds
+                        .iter()
+                        .map(|b| background_size(&b.size))
+                        .collect(),
+                ),
+            );
+            properties.insert(
+                "background-repeat".to_string(),
+                CssPropertyValue::BackgroundRepeats(
+                    backgrounds
+                        .iter()
+                        .map(|b| background_repeat(&b.repeat))
+                        .collect(),
+                ),
+            );
+        }
+        Property::BackgroundImage(images) => {
+            properties.insert(
+                "background-image".to_string(),
+                CssPropertyValue::Images(images.iter().map(css_image).collect()),
+            );
+        }
+        Property::BackgroundSize(sizes) => {
+            properties.insert(
+                "background-size".to_string(),
+                CssPropertyValue::BackgroundSizes(sizes.iter().map(background_size).collect()),
+            );
+        }
+        Property::BackgroundRepeat(repeats) => {
+            properties.insert(
+                "background-repeat".to_string(),
+                CssPropertyValue::BackgroundRepeats(
+                    repeats.iter().map(background_repeat).collect(),
+                ),
+            );
+        }
+        Property::BorderImage(border_image, _) => {
+            properties.insert(
+                "border-image-source".to_string(),
+                CssPropertyValue::Images(vec![css_image(&border_image.source)]),
+            );
+            properties.insert(
+                "border-image-slice".to_string(),
+                border_image_slice(&border_image.slice),
+            );
+            properties.insert(
+                "border-image-repeat".to_string(),
+                keyword_value(&border_image.repeat),
+            );
+        }
+        Property::BorderImageSource(image) => {
+            properties.insert(
+                "border-image-source".to_string(),
+                CssPropertyValue::Images(vec![css_image(image)]),
+            );
+        }
+        Property::BorderImageSlice(slice) => {
+            properties.insert("border-image-slice".to_string(), border_image_slice(slice));
+        }
+        Property::BorderImageRepeat(repeat) => {
+            properties.insert("border-image-repeat".to_string(), keyword_value(repeat));
+        }
+        Property::Color(color) => {
+            properties.insert("color".to_string(), CssPropertyValue::Color(color.clone()));
+        }
+        Property::FontSize(size) => {
+            properties.insert("font-size".to_string(), extract_font_size_value(size));
+        }
+        Property::Width(width) => {
+            let value = extract_size_value(width);
+            properties.insert("width".to_string(), CssPropertyValue::Length(value));
+        }
+        Property::Height(height) => {
+            let value = extract_size_value(height);
+            properties.insert("height".to_string(), CssPropertyValue::Length(value));
+        }
+        Property::MinWidth(width) => {
+            let value = extract_size_value(width);
+            properties.insert("min-width".to_string(), CssPropertyValue::Length(value));
+        }
+        Property::MinHeight(height) => {
+            let value = extract_size_value(height);
+            properties.insert("min-height".to_string(), CssPropertyValue::Length(value));
+        }
+        Property::MaxWidth(width) => {
+            let value = extract_max_size_value(width);
+            properties.insert("max-width".to_string(), CssPropertyValue::Length(value));
+        }
+        Property::MaxHeight(height) => {
+            let value = extract_max_size_value(height);
+            properties.insert("max-height".to_string(), CssPropertyValue::Length(value));
+        }
+        Property::AspectRatio(aspect_ratio) => {
+            // `auto 16 / 9` ใช้ ratio เหมือนกัน, `auto` อย่างเดียว = ไม่กำหนด
+            let value = match &aspect_ratio.ratio {
+                Some(ratio) if ratio.1 != 0.0 => CssPropertyValue::Number(ratio.0 / ratio.1),
+                _ => CssPropertyValue::String("auto".to_string()),
+            };
+            properties.insert("aspect-ratio".to_string(), value);
+        }
+        // Padding / margin shorthands แตกเป็น longhands ต่อด้าน (padding-top, margin-left, ...)
+        // logical properties ใช้ writing mode แนวนอนซ้ายไปขวาเหมือน border
+        Property::Padding(padding) => {
+            let sides = [&padding.top, &padding.right, &padding.bottom, &padding.left];
+            for (side, value) in BORDER_SIDES.iter().zip(sides) {
+                insert_spacing(properties, "padding", side, value);
+            }
+        }
+        Property::PaddingBlock(padding) => {
+            insert_spacing(properties, "padding", "top", &padding.block_start);
+            insert_spacing(properties, "padding", "bottom", &padding.block_end);
+        }
+        Property::PaddingInline(padding) => {
+            insert_spacing(properties, "padding", "left", &padding.inline_start);
+            insert_spacing(properties, "padding", "right", &padding.inline_end);
+        }
+        Property::PaddingTop(value) | Property::PaddingBlockStart(value) => {
+            insert_spacing(properties, "padding", "top", value)
+        }
+        Property::PaddingRight(value) | Property::PaddingInlineEnd(value) => {
+            insert_spacing(properties, "padding", "right", value)
+        }
+        Property::PaddingBottom(value) | Property::PaddingBlockEnd(value) => {
+            insert_spacing(properties, "padding", "bottom", value)
+        }
+        Property::PaddingLeft(value) | Property::PaddingInlineStart(value) => {
+            insert_spacing(properties, "padding", "left", value)
+        }
+        Property::Margin(margin) => {
+            let sides = [&margin.top, &margin.right, &margin.bottom, &margin.left];
+            for (side, value) in BORDER_SIDES.iter().zip(sides) {
+                insert_spacing(properties, "margin", side, value);
+            }
+        }
+        Property::MarginBlock(margin) => {
+            insert_spacing(properties, "margin", "top", &margin.block_start);
+            insert_spacing(properties, "margin", "bottom", &margin.block_end);
+        }
+        Property::MarginInline(margin) => {
+            insert_spacing(properties, "margin", "left", &margin.inline_start);
+            insert_spacing(properties, "margin", "right", &margin.inline_end);
+        }
+        Property::MarginTop(value) | Property::MarginBlockStart(value) => {
+            insert_spacing(properties, "margin", "top", value)
+        }
+        Property::MarginRight(value) | Property::MarginInlineEnd(value) => {
+            insert_spacing(properties, "margin", "right", value)
+        }
+        Property::MarginBottom(value) | Property::MarginBlockEnd(value) => {
+            insert_spacing(properties, "margin", "bottom", value)
+        }
+        Property::MarginLeft(value) | Property::MarginInlineStart(value) => {
+            insert_spacing(properties, "margin", "left", value)
+        }
+        Property::BorderRadius(border_radius, _) => {
+            let (top_left, top_right, bottom_right, bottom_left) =
+                extract_corner_values(border_radius);
+            properties.insert(
+                "border-radius".to_string(),
+                CssPropertyValue::Corner {
+                    top_left,
+                    top_right,
+                    bottom_right,
+                    bottom_left,
+                },
+            );
+        }
+        // Border shorthands แตกเป็น longhands ต่อด้าน (border-top-width, border-top-style, ...)
+        // logical properties ใช้ writing mode แนวนอนซ้ายไปขวา
+        Property::Border(border) => insert_border(properties, &BORDER_SIDES, border),
+        Property::BorderTop(border) => insert_border(properties, &["top"], border),
+        Property::BorderBlockStart(border) => insert_border(properties, &["top"], border),
+        Property::BorderRight(border) => insert_border(properties, &["right"], border),
+        Property::BorderInlineEnd(border) => insert_border(properties, &["right"], border),
+        Property::BorderBottom(border) => insert_border(properties, &["bottom"], border),
+        Property::BorderBlockEnd(border) => insert_border(properties, &["bottom"], border),
+        Property::BorderLeft(border) => insert_border(properties, &["left"], border),
+        Property::BorderInlineStart(border) => insert_border(properties, &["left"], border),
+        Property::BorderBlock(border) => insert_border(properties, &["top", "bottom"], border),
+        Property::BorderInline(border) => insert_border(properties, &["left", "right"], border),
+        Property::BorderWidth(width) => {
+            let sides = [&width.top, &width.right, &width.bottom, &width.left];
+            for (side, width) in BORDER_SIDES.iter().zip(sides) {
+                insert_border_width(properties, side, width);
+            }
+        }
+        Property::BorderStyle(style) => {
+            let sides = [&style.top, &style.right, &style.bottom, &style.left];
+            for (side, style) in BORDER_SIDES.iter().zip(sides) {
+                insert_border_style(properties, side, style);
+            }
+        }
+        Property::BorderColor(color) => {
+            let sides = [&color.top, &color.right, &color.bottom, &color.left];
+            for (side, color) in BORDER_SIDES.iter().zip(sides) {
+                insert_border_color(properties, side, color);
+            }
+        }
+        Property::BorderBlockWidth(width) => {
+            insert_border_width(properties, "top", &width.start);
+            insert_border_width(properties, "bottom", &width.end);
+        }
+        Property::BorderInlineWidth(width) => {
+            insert_border_width(properties, "left", &width.start);
+            insert_border_width(properties, "right", &width.end);
+        }
+        Property::BorderBlockStyle(style) => {
+            insert_border_style(properties, "top", &style.start);
+            insert_border_style(properties, "bottom", &style.end);
+        }
+        Property::BorderInlineStyle(style) => {
+            insert_border_style(properties, "left", &style.start);
+            insert_border_style(properties, "right", &style.end);
+        }
+        Property::BorderBlockColor(color) => {
+            insert_border_color(properties, "top", &color.start);
+            insert_border_color(properties, "bottom", &color.end);
+        }
+        Property::BorderInlineColor(color) => {
+            insert_border_color(properties, "left", &color.start);
+            insert_border_color(properties, "right", &color.end);
+        }
+        Property::BorderTopWidth(width) | Property::BorderBlockStartWidth(width) => {
+            insert_border_width(properties, "top", width)
+        }
+        Property::BorderRightWidth(width) | Property::BorderInlineEndWidth(width) => {
+            insert_border_width(properties, "right", width)
+        }
+        Property::BorderBottomWidth(width) | Property::BorderBlockEndWidth(width) => {
+            insert_border_width(properties, "bottom", width)
+        }
+        Property::BorderLeftWidth(width) | Property::BorderInlineStartWidth(width) => {
+            insert_border_width(properties, "left", width)
+        }
+        Property::BorderTopStyle(style) | Property::BorderBlockStartStyle(style) => {
+            insert_border_style(properties, "top", style)
+        }
+        Property::BorderRightStyle(style) | Property::BorderInlineEndStyle(style) => {
+            insert_border_style(properties, "right", style)
+        }
+        Property::BorderBottomStyle(style) | Property::BorderBlockEndStyle(style) => {
+            insert_border_style(properties, "bottom", style)
+        }
+        Property::BorderLeftStyle(style) | Property::BorderInlineStartStyle(style) => {
+            insert_border_style(properties, "left", style)
+        }
+        Property::BorderTopColor(color) | Property::BorderBlockStartColor(color) => {
+            insert_border_color(properties, "top", color)
+        }
+        Property::BorderRightColor(color) | Property::BorderInlineEndColor(color) => {
+            insert_border_color(properties, "right", color)
+        }
+        Property::BorderBottomColor(color) | Property::BorderBlockEndColor(color) => {
+            insert_border_color(properties, "bottom", color)
+        }
+        Property::BorderLeftColor(color) | Property::BorderInlineStartColor(color) => {
+            insert_border_color(properties, "left", color)
+        }
+        Property::BoxShadow(shadows, _) => {
+            // Bevy วาดได้เฉพาะเงาด้านนอก
+            if shadows.iter().any(|shadow| shadow.inset) {
+                warn!("box-shadow: inset shadows are not supported and will be ignored");
+            }
+            let shadows = shadows
+                .iter()
+                .filter(|shadow| !shadow.inset)
+                .map(|shadow| CssShadow {
+                    color: shadow.color.clone(),
+                    x_offset: extract_spacing_length(&shadow.x_offset),
+                    y_offset: extract_spacing_length(&shadow.y_offset),
+                    blur: extract_spacing_length(&shadow.blur),
+                    spread: extract_spacing_length(&shadow.spread),
+                })
+                .collect();
+            properties.insert("box-shadow".to_string(), CssPropertyValue::Shadows(shadows));
+        }
+        Property::Outline(outline) => {
+            properties.insert(
+                "outline-width".to_string(),
+                CssPropertyValue::Length(border_side_width(&outline.width)),
+            );
+            properties.insert("outline-style".to_string(), keyword_value(&outline.style));
+            properties.insert(
+                "outline-color".to_string(),
+                CssPropertyValue::Color(outline.color.clone()),
+            );
+        }
+        Property::OutlineWidth(width) => {
+            properties.insert(
+                "outline-width".to_string(),
+                CssPropertyValue::Length(border_side_width(width)),
+            );
+        }
+        Property::OutlineStyle(style) => {
+            properties.insert("outline-style".to_string(), keyword_value(style));
+        }
+        Property::OutlineColor(color) => {
+            properties.insert(
+                "outline-color".to_string(),
+                CssPropertyValue::Color(color.clone()),
+            );
+        }
+        Property::FontFamily(families) => {
+            properties.insert(
+                "font-family".to_string(),
+                CssPropertyValue::String(font_family_list(families)),
+            );
+        }
+        Property::FontWeight(weight) => {
+            properties.insert("font-weight".to_string(), font_weight_value(weight));
+        }
+        Property::FontStyle(style) => {
+            properties.insert("font-style".to_string(), keyword_value(style));
+        }
+        Property::LineHeight(line_height) => {
+            properties.insert("line-height".to_string(), line_height_value(line_height));
+        }
+        Property::Font(font) => {
+            properties.insert("font-size".to_string(), extract_font_size_value(&font.size));
+            properties.insert(
+                "font-family".to_string(),
+                CssPropertyValue::String(font_family_list(&font.family)),
+            );
+            properties.insert("font-weight".to_string(), font_weight_value(&font.weight));
+            properties.insert("font-style".to_string(), keyword_value(&font.style));
+            properties.insert(
+                "line-height".to_string(),
+                line_height_value(&font.line_height),
+            );
+        }
+        Property::TextAlign(align) => {
+            properties.insert("text-align".to_string(), keyword_value(align));
+        }
+        Property::WhiteSpace(white_space) => {
+            properties.insert("white-space".to_string(), keyword_value(white_space));
+        }
+        Property::TextOverflow(overflow, _) => {
+            properties.insert("text-overflow".to_string(), keyword_value(overflow));
+        }
+        // เก็บและสืบทอดไว้ แต่ Bevy text ยังไม่มีระยะห่างระหว่างตัวอักษรให้ใช้
+        Property::LetterSpacing(spacing) => {
+            let value = match spacing {
+                Spacing::Normal => CssLength::Val(Val::Px(0.0)),
+                Spacing::Length(length) => {
+                    warn!("letter-spacing is not rendered by Bevy text and will be ignored");
+                    extract_spacing_length(length)
                 }
-                DimensionPercentage::Percentage(pct) => pct.0 * 100.0,
-                DimensionPercentage::Calc(_) => {
-                    // handle calc() expressions
-                    10.0
+            };
+            properties.insert(
+                "letter-spacing".to_string(),
+                CssPropertyValue::Length(value),
+            );
+        }
+        Property::Overflow(overflow) => {
+            properties.insert("overflow-x".to_string(), keyword_value(&overflow.x));
+            properties.insert("overflow-y".to_string(), keyword_value(&overflow.y));
+        }
+        Property::OverflowX(overflow) => {
+            properties.insert("overflow-x".to_string(), keyword_value(overflow));
+        }
+        Property::OverflowY(overflow) => {
+            properties.insert("overflow-y".to_string(), keyword_value(overflow));
+        }
+        Property::Opacity(opacity) => {
+            properties.insert("opacity".to_string(), CssPropertyValue::Number(opacity.0));
+        }
+        Property::Visibility(visibility) => {
+            properties.insert("visibility".to_string(), keyword_value(visibility));
+        }
+        Property::Display(display) => {
+            properties.insert("display".to_string(), keyword_value(display));
+        }
+        Property::FlexDirection(direction, _) => {
+            properties.insert("flex-direction".to_string(), keyword_value(direction));
+        }
+        Property::FlexWrap(wrap, _) => {
+            properties.insert("flex-wrap".to_string(), keyword_value(wrap));
+        }
+        Property::FlexFlow(flow, _) => {
+            properties.insert("flex-direction".to_string(), keyword_value(&flow.direction));
+            properties.insert("flex-wrap".to_string(), keyword_value(&flow.wrap));
+        }
+        Property::FlexGrow(grow, _) => {
+            properties.insert("flex-grow".to_string(), CssPropertyValue::Number(*grow));
+        }
+        Property::FlexShrink(shrink, _) => {
+            properties.insert("flex-shrink".to_string(), CssPropertyValue::Number(*shrink));
+        }
+        Property::FlexBasis(basis, _) => {
+            properties.insert(
+                "flex-basis".to_string(),
+                CssPropertyValue::Length(extract_length_or_auto(basis)),
+            );
+        }
+        Property::Flex(flex, _) => {
+            properties.insert("flex-grow".to_string(), CssPropertyValue::Number(flex.grow));
+            properties.insert(
+                "flex-shrink".to_string(),
+                CssPropertyValue::Number(flex.shrink),
+            );
+            properties.insert(
+                "flex-basis".to_string(),
+                CssPropertyValue::Length(extract_length_or_auto(&flex.basis)),
+            );
+        }
+        Property::JustifyContent(justify, _) => {
+            properties.insert("justify-content".to_string(), keyword_value(justify));
+        }
+        Property::AlignItems(align, _) => {
+            properties.insert("align-items".to_string(), keyword_value(align));
+        }
+        Property::AlignSelf(align, _) => {
+            properties.insert("align-self".to_string(), keyword_value(align));
+        }
+        Property::AlignContent(align, _) => {
+            properties.insert("align-content".to_string(), keyword_value(align));
+        }
+        Property::RowGap(gap) => {
+            properties.insert("row-gap".to_string(), extract_gap_value(gap));
+        }
+        Property::ColumnGap(gap) => {
+            properties.insert("column-gap".to_string(), extract_gap_value(gap));
+        }
+        Property::Gap(gap) => {
+            properties.insert("row-gap".to_string(), extract_gap_value(&gap.row));
+            properties.insert("column-gap".to_string(), extract_gap_value(&gap.column));
+        }
+        Property::JustifyItems(justify) => {
+            properties.insert("justify-items".to_string(), keyword_value(justify));
+        }
+        Property::JustifySelf(justify) => {
+            properties.insert("justify-self".to_string(), keyword_value(justify));
+        }
+        Property::GridTemplateColumns(tracks) => {
+            properties.insert("grid-template-columns".to_string(), grid_template(tracks));
+        }
+        Property::GridTemplateRows(tracks) => {
+            properties.insert("grid-template-rows".to_string(), grid_template(tracks));
+        }
+        Property::GridTemplate(template) => {
+            properties.insert(
+                "grid-template-rows".to_string(),
+                grid_template(&template.rows),
+            );
+            properties.insert(
+                "grid-template-columns".to_string(),
+                grid_template(&template.columns),
+            );
+        }
+        Property::GridAutoColumns(tracks) => {
+            properties.insert("grid-auto-columns".to_string(), grid_auto_tracks(tracks));
+        }
+        Property::GridAutoRows(tracks) => {
+            properties.insert("grid-auto-rows".to_string(), grid_auto_tracks(tracks));
+        }
+        Property::GridAutoFlow(flow) => {
+            properties.insert("grid-auto-flow".to_string(), keyword_value(flow));
+        }
+        Property::Grid(grid) => {
+            properties.insert("grid-template-rows".to_string(), grid_template(&grid.rows));
+            properties.insert(
+                "grid-template-columns".to_string(),
+                grid_template(&grid.columns),
+            );
+            properties.insert(
+                "grid-auto-rows".to_string(),
+                grid_auto_tracks(&grid.auto_rows),
+            );
+            properties.insert(
+                "grid-auto-columns".to_string(),
+                grid_auto_tracks(&grid.auto_columns),
+            );
+            properties.insert("grid-auto-flow".to_string(), keyword_value(&grid.auto_flow));
+        }
+        Property::GridRowStart(line) => {
+            properties.insert("grid-row-start".to_string(), grid_line(line));
+        }
+        Property::GridRowEnd(line) => {
+            properties.insert("grid-row-end".to_string(), grid_line(line));
+        }
+        Property::GridColumnStart(line) => {
+            properties.insert("grid-column-start".to_string(), grid_line(line));
+        }
+        Property::GridColumnEnd(line) => {
+            properties.insert("grid-column-end".to_string(), grid_line(line));
+        }
+        Property::GridRow(row) => {
+            properties.insert("grid-row-start".to_string(), grid_line(&row.start));
+            properties.insert("grid-row-end".to_string(), grid_line(&row.end));
+        }
+        Property::GridColumn(column) => {
+            properties.insert("grid-column-start".to_string(), grid_line(&column.start));
+            properties.insert("grid-column-end".to_string(), grid_line(&column.end));
+        }
+        Property::GridArea(area) => {
+            properties.insert("grid-row-start".to_string(), grid_line(&area.row_start));
+            properties.insert(
+                "grid-column-start".to_string(),
+                grid_line(&area.column_start),
+            );
+            properties.insert("grid-row-end".to_string(), grid_line(&area.row_end));
+            properties.insert("grid-column-end".to_string(), grid_line(&area.column_end));
+        }
+        Property::Position(position) => {
+            properties.insert("position".to_string(), keyword_value(position));
+        }
+        Property::Top(top) => {
+            properties.insert(
+                "top".to_string(),
+                CssPropertyValue::Length(extract_length_or_auto(top)),
+            );
+        }
+        Property::Right(right) => {
+            properties.insert(
+                "right".to_string(),
+                CssPropertyValue::Length(extract_length_or_auto(right)),
+            );
+        }
+        Property::Bottom(bottom) => {
+            properties.insert(
+                "bottom".to_string(),
+                CssPropertyValue::Length(extract_length_or_auto(bottom)),
+            );
+        }
+        Property::Left(left) => {
+            properties.insert(
+                "left".to_string(),
+                CssPropertyValue::Length(extract_length_or_auto(left)),
+            );
+        }
+        Property::Inset(inset) => {
+            for (name, value) in [
+                ("top", &inset.top),
+                ("right", &inset.right),
+                ("bottom", &inset.bottom),
+                ("left", &inset.left),
+            ] {
+                properties.insert(
+                    name.to_string(),
+                    CssPropertyValue::Length(extract_length_or_auto(value)),
+                );
+            }
+        }
+        Property::ZIndex(z_index) => {
+            let value = match z_index {
+                ZIndex::Auto => CssPropertyValue::String("auto".to_string()),
+                ZIndex::Integer(value) => CssPropertyValue::Integer(*value),
+            };
+            properties.insert("z-index".to_string(), value);
+        }
+        Property::Unparsed(unparsed) => {
+            // CSS-wide keywords (inherit, initial, unset) และค่าที่มี var() มาเป็น unparsed token list
+            let text = declaration
+                .value_to_css_string(PrinterOptions::default())
+                .unwrap_or_default();
+            let name = unparsed.property_id.name().to_string();
+            if let Ok(keyword) = CSSWideKeyword::parse_string(text.trim()) {
+                // keyword บน shorthand มีผลกับทุก longhand
+                for name in property_names(&name) {
+                    properties.insert(name, CssPropertyValue::Keyword(keyword));
+                }
+            } else if text.contains("var(") {
+                for longhand in property_names(&name) {
+                    properties.insert(
+                        longhand,
+                        CssPropertyValue::Var {
+                            property: name.clone(),
+                            value: text.clone(),
+                        },
+                    );
                 }
             }
         }
-        Size::Auto => 10.0,
-        Size::MaxContent(_) => 10.0,
-        Size::MinContent(_) => 10.0,
-        Size::FitContent(_) => 10.0,
-        Size::Stretch(_) => 10.0,
-        _ => 10.0,
+        Property::Custom(custom) => {
+            let text = declaration
+                .value_to_css_string(PrinterOptions::default())
+                .unwrap_or_default();
+            match &custom.name {
+                CustomPropertyName::Custom(name) => {
+                    properties.insert(
+                        name.as_ref().to_string(),
+                        CssPropertyValue::Custom(text.trim().to_string()),
+                    );
+                }
+                // lightningcss ไม่รู้จัก outline-offset จึงต้อง parse เอง
+                CustomPropertyName::Unknown(name) if name.as_ref() == "outline-offset" => {
+                    if text.contains("var(") {
+                        properties.insert(
+                            name.as_ref().to_string(),
+                            CssPropertyValue::Var {
+                                property: name.as_ref().to_string(),
+                                value: text,
+                            },
+                        );
+                    } else if let Ok(offset) = Length::parse_string(text.trim()) {
+                        properties.insert(
+                            name.as_ref().to_string(),
+                            CssPropertyValue::Length(extract_spacing_length(&offset)),
+                        );
+                    }
+                }
+                // overflow-clip-margin: <visual-box> || <length>
+                CustomPropertyName::Unknown(name) if name.as_ref() == "overflow-clip-margin" => {
+                    if text.contains("var(") {
+                        properties.insert(
+                            name.as_ref().to_string(),
+                            CssPropertyValue::Var {
+                                property: name.as_ref().to_string(),
+                                value: text,
+                            },
+                        );
+                        return;
+                    }
+                    for token in text.split_whitespace() {
+                        if token.ends_with("-box") {
+                            properties.insert(
+                                "overflow-clip-margin-box".to_string(),
+                                CssPropertyValue::String(token.to_ascii_lowercase()),
+                            );
+                        } else if let Ok(margin) = Length::parse_string(token) {
+                            properties.insert(
+                                name.as_ref().to_string(),
+                                CssPropertyValue::Length(extract_spacing_length(&margin)),
+                            );
+                        }
+                    }
+                }
+                // object-fit ของ <img> ก็เช่นกัน
+                CustomPropertyName::Unknown(name) if name.as_ref() == "object-fit" => {
+                    properties.insert(
+                        name.as_ref().to_string(),
+                        CssPropertyValue::String(text.trim().to_ascii_lowercase()),
+                    );
+                }
+                CustomPropertyName::Unknown(_) => {}
+            }
+        }
+        _ => {} // Handle other properties as needed
     }
 }
 
-fn extract_length_value(size: &lightningcss::values::length::LengthPercentageOrAuto) -> Val {
+// font-size แบบ em / % / smaller / larger คำนวณเทียบกับ font-size ของ parent ตอน compute styles
+fn extract_font_size_value(size: &FontSize) -> CssPropertyValue {
     match size {
-        lightningcss::values::length::LengthPercentageOrAuto::LengthPercentage(lp) => match lp {
-            lightningcss::values::length::LengthPercentage::Dimension(l) => {
-                Val::Px(l.to_px().unwrap_or(0.0))
+        // % ของ font-size มีค่าเท่ากับ em
+        FontSize::Length(LengthPercentage::Percentage(pct)) => {
+            CssPropertyValue::Length(CssLength::Em(pct.0))
+        }
+        FontSize::Length(length) => CssPropertyValue::Length(extract_length_percentage(length)),
+        FontSize::Absolute(size) => {
+            let px = match size {
+                AbsoluteFontSize::XXSmall => 9.0,
+                AbsoluteFontSize::XSmall => 10.0,
+                AbsoluteFontSize::Small => 13.0,
+                AbsoluteFontSize::Medium => DEFAULT_FONT_SIZE,
+                AbsoluteFontSize::Large => 18.0,
+                AbsoluteFontSize::XLarge => 24.0,
+                AbsoluteFontSize::XXLarge => 32.0,
+                AbsoluteFontSize::XXXLarge => 48.0,
+            };
+            CssPropertyValue::Size(px)
+        }
+        FontSize::Relative(RelativeFontSize::Smaller) => {
+            CssPropertyValue::Length(CssLength::Em(1.0 / 1.2))
+        }
+        FontSize::Relative(RelativeFontSize::Larger) => {
+            CssPropertyValue::Length(CssLength::Em(1.2))
+        }
+    }
+}
+
+fn extract_spacing_length(length: &Length) -> CssLength {
+    match length {
+        Length::Value(value) => extract_dimension(value),
+        Length::Calc(calc) => calc_length(calc, &extract_spacing_length),
+    }
+}
+
+fn keyword_value<T: ToCss>(value: &T) -> CssPropertyValue {
+    CssPropertyValue::String(
+        value
+            .to_css_string(PrinterOptions::default())
+            .unwrap_or_default(),
+    )
+}
+
+fn font_family_list(families: &[FontFamily]) -> String {
+    families
+        .iter()
+        .filter_map(|family| family.to_css_string(PrinterOptions::default()).ok())
+        .map(|family| family.trim_matches(|c| c == '"' || c == '\'').to_string())
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+// ไม่มี font-family หรือ src ที่โหลดได้ = ไม่ใช้ rule นี้
+fn css_font_face(properties: &[FontFaceProperty]) -> Option<CssFontFace> {
+    let mut family = None;
+    let mut src = None;
+    let mut weight = 400.0;
+    let mut italic = false;
+    for property in properties {
+        match property {
+            FontFaceProperty::FontFamily(name) => {
+                family = Some(font_family_list(std::slice::from_ref(name)));
+            }
+            // Bevy โหลดได้เฉพาะ TrueType / OpenType, local() ไม่รองรับ
+            FontFaceProperty::Source(sources) => {
+                src = sources.iter().find_map(|source| match source {
+                    Source::Url(source) if !is_web_font(source) => Some(
+                        source
+                            .url
+                            .url
+                            .trim_start_matches("./")
+                            .trim_start_matches('/')
+                            .to_string(),
+                    ),
+                    _ => None,
+                });
+            }
+            // ช่วง weight ของ variable font ใช้ค่าแรก
+            FontFaceProperty::FontWeight(range) => {
+                if let CssPropertyValue::Number(value) = font_weight_value(&range.0) {
+                    weight = value;
+                }
             }
-            lightningcss::values::length::LengthPercentage::Percentage(p) => {
-                Val::Percent(p.0 * 100.0)
+            FontFaceProperty::FontStyle(style) => {
+                italic = !matches!(style, FontFaceStyle::Normal);
+            }
+            _ => {}
+        }
+    }
+    Some(CssFontFace {
+        family: family?,
+        src: src?,
+        weight,
+        italic,
+    })
+}
+
+fn is_web_font(source: &UrlSource) -> bool {
+    let format = source
+        .format
+        .as_ref()
+        .and_then(|format| format.to_css_string(PrinterOptions::default()).ok())
+        .unwrap_or_default();
+    let path = source.url.url.to_lowercase();
+    format.contains("woff")
+        || format.contains("embedded-opentype")
+        || format.contains("svg")
+        || [".woff", ".woff2", ".eot", ".svg"]
+            .iter()
+            .any(|extension| path.ends_with(extension))
+}
+
+fn font_weight_value(weight: &FontWeight) -> CssPropertyValue {
+    match weight {
+        FontWeight::Absolute(AbsoluteFontWeight::Weight(value)) => CssPropertyValue::Number(*value),
+        FontWeight::Absolute(AbsoluteFontWeight::Normal) => CssPropertyValue::Number(400.0),
+        FontWeight::Absolute(AbsoluteFontWeight::Bold) => CssPropertyValue::Number(700.0),
+        // คำนวณเทียบกับ parent ตอน compute styles
+        FontWeight::Bolder => CssPropertyValue::String("bolder".to_string()),
+        FontWeight::Lighter => CssPropertyValue::String("lighter".to_string()),
+    }
+}
+
+fn line_height_value(line_height: &LineHeight) -> CssPropertyValue {
+    match line_height {
+        LineHeight::Normal => CssPropertyValue::String("normal".to_string()),
+        LineHeight::Number(value) => CssPropertyValue::Number(*value),
+        // เปอร์เซ็นต์ของ font-size มีค่าเท่ากับตัวคูณ
+        LineHeight::Length(DimensionPercentage::Percentage(pct)) => CssPropertyValue::Number(pct.0),
+        LineHeight::Length(length) => CssPropertyValue::Length(extract_length_percentage(length)),
+    }
+}
+
+fn grid_template(tracks: &TrackSizing) -> CssPropertyValue {
+    let tracks = match tracks {
+        TrackSizing::None => Vec::new(),
+        TrackSizing::TrackList(list) => list
+            .items
+            .iter()
+            .map(|item| match item {
+                TrackListItem::TrackSize(size) => RepeatedGridTrack::from(grid_track(size)),
+                TrackListItem::TrackRepeat(repeat) => {
+                    let repetition = match repeat.count {
+                        RepeatCount::Number(count) => GridTrackRepetition::Count(count as u16),
+                        RepeatCount::AutoFill => GridTrackRepetition::AutoFill,
+                        RepeatCount::AutoFit => GridTrackRepetition::AutoFit,
+                    };
+                    let tracks: Vec<GridTrack> =
+                        repeat.track_sizes.iter().map(grid_track).collect();
+                    RepeatedGridTrack::repeat_many(repetition, tracks)
+                }
+            })
+            .collect(),
+    };
+    CssPropertyValue::GridTemplate(tracks)
+}
+
+fn grid_auto_tracks(tracks: &TrackSizeList) -> CssPropertyValue {
+    CssPropertyValue::GridTracks(tracks.0.iter().map(grid_track).collect())
+}
+
+fn grid_track(size: &TrackSize) -> GridTrack {
+    match size {
+        TrackSize::TrackBreadth(breadth) => match breadth {
+            TrackBreadth::Length(length) => {
+                let length = track_length(length);
+                GridTrack::minmax(min_track_length(length), max_track_length(length))
             }
-            lightningcss::values::length::LengthPercentage::Calc(_) => Val::Px(0.0),
+            TrackBreadth::Flex(fraction) => GridTrack::fr(*fraction),
+            TrackBreadth::MinContent => GridTrack::min_content(),
+            TrackBreadth::MaxContent => GridTrack::max_content(),
+            TrackBreadth::Auto => GridTrack::auto(),
+        },
+        TrackSize::MinMax { min, max } => {
+            GridTrack::minmax(min_track_sizing(min), max_track_sizing(max))
+        }
+        TrackSize::FitContent(length) => match track_length(length) {
+            Val::Px(limit) => GridTrack::fit_content_px(limit),
+            Val::Percent(limit) => GridTrack::fit_content_percent(limit),
+            _ => GridTrack::auto(),
         },
-        _ => Val::Px(0.0),
     }
 }
 
-fn extract_value(dim_pct: &DimensionPercentage<lightningcss::values::length::LengthValue>) -> Val {
-    match dim_pct {
-        DimensionPercentage::Dimension(len) => Val::Px(len.to_px().unwrap_or(0.0)),
-        DimensionPercentage::Percentage(pct) => Val::Percent(pct.0),
-        DimensionPercentage::Calc(_) => Val::Px(0.0),
+// Grid tracks ไม่ได้ผ่าน compute styles → em / rem เทียบกับ font-size เริ่มต้น
+fn track_length(length: &LengthPercentage) -> Val {
+    extract_length_percentage(length).resolve(DEFAULT_FONT_SIZE, DEFAULT_FONT_SIZE)
+}
+
+fn min_track_length(length: Val) -> MinTrackSizingFunction {
+    match length {
+        Val::Px(value) => MinTrackSizingFunction::Px(value),
+        Val::Percent(value) => MinTrackSizingFunction::Percent(value),
+        Val::Vw(value) => MinTrackSizingFunction::Vw(value),
+        Val::Vh(value) => MinTrackSizingFunction::Vh(value),
+        Val::VMin(value) => MinTrackSizingFunction::VMin(value),
+        Val::VMax(value) => MinTrackSizingFunction::VMax(value),
+        Val::Auto => MinTrackSizingFunction::Auto,
+    }
+}
+
+fn max_track_length(length: Val) -> MaxTrackSizingFunction {
+    match length {
+        Val::Px(value) => MaxTrackSizingFunction::Px(value),
+        Val::Percent(value) => MaxTrackSizingFunction::Percent(value),
+        Val::Vw(value) => MaxTrackSizingFunction::Vw(value),
+        Val::Vh(value) => MaxTrackSizingFunction::Vh(value),
+        Val::VMin(value) => MaxTrackSizingFunction::VMin(value),
+        Val::VMax(value) => MaxTrackSizingFunction::VMax(value),
+        Val::Auto => MaxTrackSizingFunction::Auto,
     }
 }
 
-fn extract_corner_values(
-    border_radius: &lightningcss::properties::border_radius::BorderRadius,
-) -> (Val, Val, Val, Val) {
-    let top_left = extract_value(&border_radius.top_left.0);
-    let top_right = extract_value(&border_radius.top_right.0);
-    let bottom_right = extract_value(&border_radius.bottom_right.0);
-    let bottom_left = extract_value(&border_radius.bottom_left.0);
-    (top_left, top_right, bottom_right, bottom_left)
+fn min_track_sizing(breadth: &TrackBreadth) -> MinTrackSizingFunction {
+    match breadth {
+        TrackBreadth::Length(length) => min_track_length(track_length(length)),
+        TrackBreadth::MinContent => MinTrackSizingFunction::MinContent,
+        TrackBreadth::MaxContent => MinTrackSizingFunction::MaxContent,
+        // fr ใช้เป็นค่า min ไม่ได้ตาม spec
+        _ => MinTrackSizingFunction::Auto,
+    }
+}
+
+fn max_track_sizing(breadth: &TrackBreadth) -> MaxTrackSizingFunction {
+    match breadth {
+        TrackBreadth::Length(length) => max_track_length(track_length(length)),
+        TrackBreadth::Flex(fraction) => MaxTrackSizingFunction::Fraction(*fraction),
+        TrackBreadth::MinContent => MaxTrackSizingFunction::MinContent,
+        TrackBreadth::MaxContent => MaxTrackSizingFunction::MaxContent,
+        _ => MaxTrackSizingFunction::Auto,
+    }
 }
 
-fn extract_padding_values(padding: &Property<'_>) -> (Val, Val, Val, Val) {
-    match padding {
-        Property::Padding(p) => {
-            let top = extract_length_value(&p.top);
-            let right = extract_length_value(&p.right);
-            let bottom = extract_length_value(&p.bottom);
-            let left = extract_length_value(&p.left);
-            (top, right, bottom, left)
+fn grid_line(line: &GridLine) -> CssPropertyValue {
+    let value = match line {
+        GridLine::Auto => GridLineValue::Auto,
+        GridLine::Line { index, name: None } if *index != 0 => GridLineValue::Line(*index as i16),
+        GridLine::Span { index, name: None } if *index > 0 => GridLineValue::Span(*index as u16),
+        // line 0 ไม่ valid, ชื่อ line/area ยังไม่รองรับ → auto placement
+        _ => {
+            let text = line
+                .to_css_string(PrinterOptions::default())
+                .unwrap_or_default();
+            warn!("{text}: named and zero grid lines are not supported, using auto");
+            GridLineValue::Auto
         }
-        _ => (Val::Px(0.0), Val::Px(0.0), Val::Px(0.0), Val::Px(0.0)),
+    };
+    CssPropertyValue::GridLine(value)
+}
+
+// min-content / max-content / fit-content ไม่มีใน Val → ให้ layout คำนวณเอง (Auto)
+fn extract_size_value(size: &Size) -> CssLength {
+    match size {
+        Size::LengthPercentage(lp) => extract_length_percentage(lp),
+        _ => CssLength::Val(Val::Auto),
+    }
+}
+
+// `none` = ไม่จำกัดขนาด ซึ่งคือ Val::Auto ของ Bevy
+fn extract_max_size_value(size: &MaxSize) -> CssLength {
+    match size {
+        MaxSize::LengthPercentage(lp) => extract_length_percentage(lp),
+        _ => CssLength::Val(Val::Auto),
+    }
+}
+
+fn extract_length_value(size: &LengthPercentageOrAuto) -> CssLength {
+    match size {
+        LengthPercentageOrAuto::LengthPercentage(lp) => extract_length_percentage(lp),
+        _ => CssLength::Val(Val::Px(0.0)),
+    }
+}
+
+fn extract_length_or_auto(size: &LengthPercentageOrAuto) -> CssLength {
+    match size {
+        LengthPercentageOrAuto::Auto => CssLength::Val(Val::Auto),
+        LengthPercentageOrAuto::LengthPercentage(lp) => extract_length_percentage(lp),
+    }
+}
+
+fn extract_length_percentage(lp: &LengthPercentage) -> CssLength {
+    match lp {
+        LengthPercentage::Dimension(l) => extract_dimension(l),
+        LengthPercentage::Percentage(p) => CssLength::Val(Val::Percent(p.0 * 100.0)),
+        LengthPercentage::Calc(calc) => calc_length(calc, &extract_length_percentage),
+    }
+}
+
+fn extract_gap_value(gap: &GapValue) -> CssPropertyValue {
+    match gap {
+        GapValue::Normal => CssPropertyValue::Length(CssLength::Val(Val::Px(0.0))),
+        GapValue::LengthPercentage(lp) => CssPropertyValue::Length(extract_length_percentage(lp)),
     }
 }
 
-fn extract_margin_values(padding: &Property<'_>) -> (Val, Val, Val, Val) {
-    match padding {
-        Property::Margin(m) => {
-            let top = extract_length_value(&m.top);
-            let right = extract_length_value(&m.right);
-            let bottom = extract_length_value(&m.bottom);
-            let left = extract_length_value(&m.left);
-            (top, right, bottom, left)
+// math function ที่ยังไม่รองรับ (round(), mod(), rem(), ...) ได้ auto แทนการเดาค่า
+fn calc_length<V>(calc: &Calc<V>, leaf: &impl Fn(&V) -> CssLength) -> CssLength
+where
+    Calc<V>: ToCss,
+{
+    match calc_expression(calc, leaf) {
+        Some(calc) => CssLength::Calc(Box::new(calc)),
+        None => {
+            let text = calc
+                .to_css_string(PrinterOptions::default())
+                .unwrap_or_default();
+            warn!("{text}: unsupported math function, using auto");
+            CssLength::Val(Val::Auto)
         }
-        _ => (Val::Px(0.0), Val::Px(0.0), Val::Px(0.0), Val::Px(0.0)),
     }
 }
+
+fn calc_expression<V>(calc: &Calc<V>, leaf: &impl Fn(&V) -> CssLength) -> Option<CalcLength> {
+    let expression = match calc {
+        Calc::Value(value) => CalcLength::Length(leaf(value)),
+        Calc::Number(number) => CalcLength::Number(*number),
+        Calc::Sum(a, b) => CalcLength::Sum(
+            Box::new(calc_expression(a, leaf)?),
+            Box::new(calc_expression(b, leaf)?),
+        ),
+        Calc::Product(factor, calc) => {
+            CalcLength::Product(*factor, Box::new(calc_expression(calc, leaf)?))
+        }
+        Calc::Function(function) => {
+            let (function, args): (CalcFunction, Vec<&Calc<V>>) = match function.as_ref() {
+                MathFunction::Calc(calc) => return calc_expression(calc, leaf),
+                MathFunction::Min(args) => (CalcFunction::Min, args.iter().collect()),
+                MathFunction::Max(args) => (CalcFunction::Max, args.iter().collect()),
+                MathFunction::Clamp(min, value, max) => {
+                    (CalcFunction::Clamp, vec![min, value, max])
+                }
+                MathFunction::Abs(value) => (CalcFunction::Abs, vec![value]),
+                _ => return None,
+            };
+            CalcLength::Function(
+                function,
+                args.into_iter()
+                    .map(|arg| calc_expression(arg, leaf))
+                    .collect::<Option<_>>()?,
+            )
+        }
+    };
+    Some(expression)
+}
+
+// หน่วยที่ขึ้นกับ font metrics (ch, ex, cap, ic, lh) ประมาณเป็น em เพราะไม่รู้ค่าจริงของ font
+fn extract_dimension(length: &LengthValue) -> CssLength {
+    let val = match length {
+        LengthValue::Em(v) | LengthValue::Ic(v) => return CssLength::Em(*v),
+        LengthValue::Ch(v) | LengthValue::Ex(v) => return CssLength::Em(v * 0.5),
+        LengthValue::Cap(v) => return CssLength::Em(v * 0.7),
+        LengthValue::Lh(v) => return CssLength::Em(v * 1.2),
+        LengthValue::Rem(v) | LengthValue::Ric(v) => return CssLength::Rem(*v),
+        LengthValue::Rch(v) | LengthValue::Rex(v) => return CssLength::Rem(v * 0.5),
+        LengthValue::Rcap(v) => return CssLength::Rem(v * 0.7),
+        LengthValue::Rlh(v) => return CssLength::Rem(v * 1.2),
+        LengthValue::Vw(v) | LengthValue::Lvw(v) | LengthValue::Svw(v) | LengthValue::Dvw(v) => {
+            Val::Vw(*v)
+        }
+        LengthValue::Vh(v) | LengthValue::Lvh(v) | LengthValue::Svh(v) | LengthValue::Dvh(v) => {
+            Val::Vh(*v)
+        }
+        LengthValue::Vmin(v)
+        | LengthValue::Lvmin(v)
+        | LengthValue::Svmin(v)
+        | LengthValue::Dvmin(v) => Val::VMin(*v),
+        LengthValue::Vmax(v)
+        | LengthValue::Lvmax(v)
+        | LengthValue::Svmax(v)
+        | LengthValue::Dvmax(v) => Val::VMax(*v),
+        _ => Val::Px(length.to_px().unwrap_or(0.0)),
+    };
+    CssLength::Val(val)
+}
+
+// margin: auto ใช้จัดกึ่งกลางได้, padding ไม่มี auto จึงเป็น 0
+fn insert_spacing(
+    properties: &mut HashMap<String, CssPropertyValue>,
+    name: &str,
+    side: &str,
+    value: &LengthPercentageOrAuto,
+) {
+    let length = match name {
+        "margin" => extract_length_or_auto(value),
+        _ => extract_length_value(value),
+    };
+    properties.insert(format!("{name}-{side}"), CssPropertyValue::Length(length));
+}
+
+fn insert_border<const P: u8>(
+    properties: &mut HashMap<String, CssPropertyValue>,
+    sides: &[&str],
+    border: &GenericBorder<LineStyle, P>,
+) {
+    for side in sides {
+        insert_border_width(properties, side, &border.width);
+        insert_border_style(properties, side, &border.style);
+        insert_border_color(properties, side, &border.color);
+    }
+}
+
+fn insert_border_width(
+    properties: &mut HashMap<String, CssPropertyValue>,
+    side: &str,
+    width: &BorderSideWidth,
+) {
+    properties.insert(
+        format!("border-{side}-width"),
+        CssPropertyValue::Length(border_side_width(width)),
+    );
+}
+
+// thin / medium / thick ตามค่าที่ browser ส่วนใหญ่ใช้
+fn border_side_width(width: &BorderSideWidth) -> CssLength {
+    match width {
+        BorderSideWidth::Thin => CssLength::Val(Val::Px(1.0)),
+        BorderSideWidth::Medium => CssLength::Val(Val::Px(3.0)),
+        BorderSideWidth::Thick => CssLength::Val(Val::Px(5.0)),
+        BorderSideWidth::Length(length) => extract_spacing_length(length),
+    }
+}
+
+fn insert_border_style(
+    properties: &mut HashMap<String, CssPropertyValue>,
+    side: &str,
+    style: &LineStyle,
+) {
+    properties.insert(format!("border-{side}-style"), keyword_value(style));
+}
+
+fn insert_border_color(
+    properties: &mut HashMap<String, CssPropertyValue>,
+    side: &str,
+    color: &CssColor,
+) {
+    properties.insert(
+        format!("border-{side}-color"),
+        CssPropertyValue::Color(color.clone()),
+    );
+}
+
+fn css_image(image: &Image) -> CssImage {
+    match image {
+        Image::None => CssImage::None,
+        Image::Url(url) => CssImage::Url(
+            url.url
+                .trim_start_matches("./")
+                .trim_start_matches('/')
+                .to_string(),
+        ),
+        Image::Gradient(gradient) => match gradient.as_ref() {
+            Gradient::Linear(linear) => linear_gradient(linear, false),
+            Gradient::RepeatingLinear(linear) => linear_gradient(linear, true),
+            Gradient::Radial(radial) => radial_gradient(radial, false),
+            Gradient::RepeatingRadial(radial) => radial_gradient(radial, true),
+            _ => {
+                warn!("only linear-gradient() and radial-gradient() are supported");
+                CssImage::None
+            }
+        },
+        // image-set() ใช้ตัวเลือกแรก
+        Image::ImageSet(set) => set
+            .options
+            .first()
+            .map_or(CssImage::None, |option| css_image(&option.image)),
+    }
+}
+
+fn linear_gradient(gradient: &LinearGradient, repeating: bool) -> CssImage {
+    let shape = match &gradient.direction {
+        LineDirection::Angle(angle) => GradientShape::Linear(angle.to_radians()),
+        LineDirection::Vertical(VerticalPositionKeyword::Top) => GradientShape::Linear(0.0),
+        LineDirection::Vertical(VerticalPositionKeyword::Bottom) => GradientShape::Linear(PI),
+        LineDirection::Horizontal(HorizontalPositionKeyword::Right) => {
+            GradientShape::Linear(FRAC_PI_2)
+        }
+        LineDirection::Horizontal(HorizontalPositionKeyword::Left) => {
+            GradientShape::Linear(-FRAC_PI_2)
+        }
+        LineDirection::Corner {
+            horizontal,
+            vertical,
+        } => GradientShape::LinearCorner(Vec2::new(
+            match horizontal {
+                HorizontalPositionKeyword::Left => -1.0,
+                HorizontalPositionKeyword::Right => 1.0,
+            },
+            match vertical {
+                VerticalPositionKeyword::Top => -1.0,
+                VerticalPositionKeyword::Bottom => 1.0,
+            },
+        )),
+    };
+    CssImage::Gradient(CssGradient {
+        shape,
+        repeating,
+        stops: color_stops(&gradient.items),
+    })
+}
+
+fn radial_gradient(gradient: &RadialGradient, repeating: bool) -> CssImage {
+    let (circle, extent) = match &gradient.shape {
+        EndingShape::Circle(Circle::Radius(radius)) => {
+            let radius = extract_spacing_length(radius);
+            (true, RadialExtent::Radius(radius.clone(), radius))
+        }
+        EndingShape::Circle(Circle::Extent(extent)) => (true, radial_extent(extent)),
+        EndingShape::Ellipse(Ellipse::Size { x, y }) => (
+            false,
+            RadialExtent::Radius(extract_length_percentage(x), extract_length_percentage(y)),
+        ),
+        EndingShape::Ellipse(Ellipse::Extent(extent)) => (false, radial_extent(extent)),
+    };
+    let center = Vec2::new(
+        position_fraction(&gradient.position.x, |side| {
+            *side == HorizontalPositionKeyword::Right
+        }),
+        position_fraction(&gradient.position.y, |side| {
+            *side == VerticalPositionKeyword::Bottom
+        }),
+    );
+    CssImage::Gradient(CssGradient {
+        shape: GradientShape::Radial {
+            circle,
+            extent,
+            center,
+        },
+        repeating,
+        stops: color_stops(&gradient.items),
+    })
+}
+
+fn radial_extent(extent: &ShapeExtent) -> RadialExtent {
+    match extent {
+        ShapeExtent::ClosestSide => RadialExtent::ClosestSide,
+        ShapeExtent::FarthestSide => RadialExtent::FarthestSide,
+        ShapeExtent::ClosestCorner => RadialExtent::ClosestCorner,
+        ShapeExtent::FarthestCorner => RadialExtent::FarthestCorner,
+    }
+}
+
+// ตำแหน่ง "at ..." เป็นสัดส่วนของกล่อง — offset แบบ px ยังไม่รองรับ (ใช้ขอบนั้นแทน)
+fn position_fraction<S>(component: &PositionComponent<S>, is_end: impl Fn(&S) -> bool) -> f32 {
+    let fraction = |offset: &Option<LengthPercentage>| match offset {
+        Some(LengthPercentage::Percentage(percent)) => percent.0,
+        _ => 0.0,
+    };
+    match component {
+        PositionComponent::Center => 0.5,
+        PositionComponent::Length(LengthPercentage::Percentage(percent)) => percent.0,
+        PositionComponent::Length(_) => 0.5,
+        PositionComponent::Side { side, offset } if is_end(side) => 1.0 - fraction(offset),
+        PositionComponent::Side { offset, .. } => fraction(offset),
+    }
+}
+
+// transition hint ยังไม่รองรับ (ใช้จุดกึ่งกลางระหว่าง stops)
+fn color_stops(items: &[GradientItem<LengthPercentage>]) -> Vec<CssColorStop> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            GradientItem::ColorStop(stop) => Some(CssColorStop {
+                color: stop.color.clone(),
+                position: stop.position.as_ref().map(extract_length_percentage),
+            }),
+            GradientItem::Hint(_) => None,
+        })
+        .collect()
+}
+
+fn background_size(size: &BackgroundSize) -> CssBackgroundSize {
+    match size {
+        BackgroundSize::Cover => CssBackgroundSize::Cover,
+        BackgroundSize::Contain => CssBackgroundSize::Contain,
+        BackgroundSize::Explicit { width, height } => CssBackgroundSize::Explicit(
+            extract_length_or_auto(width),
+            extract_length_or_auto(height),
+        ),
+    }
+}
+
+// space / round ปูภาพเหมือน repeat
+fn background_repeat(repeat: &BackgroundRepeat) -> (bool, bool) {
+    (
+        repeat.x != BackgroundRepeatKeyword::NoRepeat,
+        repeat.y != BackgroundRepeatKeyword::NoRepeat,
+    )
+}
+
+// ตัวเลขคือ px ของภาพ, % เทียบกับขนาดภาพ
+fn border_image_slice(slice: &BorderImageSlice) -> CssPropertyValue {
+    let length = |offset: &NumberOrPercentage| match offset {
+        NumberOrPercentage::Number(number) => CssLength::Val(Val::Px(*number)),
+        NumberOrPercentage::Percentage(percent) => CssLength::Val(Val::Percent(percent.0 * 100.0)),
+    };
+    let offsets = &slice.offsets;
+    CssPropertyValue::Rect {
+        top: length(&offsets.0),
+        right: length(&offsets.1),
+        bottom: length(&offsets.2),
+        left: length(&offsets.3),
+    }
+}
+
+fn extract_corner_values(
+    border_radius: &lightningcss::properties::border_radius::BorderRadius,
+) -> (CssLength, CssLength, CssLength, CssLength) {
+    let top_left = extract_length_percentage(&border_radius.top_left.0);
+    let top_right = extract_length_percentage(&border_radius.top_right.0);
+    let bottom_right = extract_length_percentage(&border_radius.bottom_right.0);
+    let bottom_left = extract_length_percentage(&border_radius.bottom_left.0);
+    (top_left, top_right, bottom_right, bottom_left)
+}
@@ -1,11 +1,21 @@
 use crate::html_ui_builder::utils::extract_border_radius;
 
-use super::css::CssStyleSheet;
+use super::background::{BackgroundLayer, ImageFit};
+use super::calc::CalcStyles;
+use super::css::{CssPropertyValue, CssStyleSheet, DEFAULT_FONT_SIZE};
+use super::font::FontQuery;
+use super::selector::ElementState;
 use super::utils::{
-    compute_element_styles, convert_css_to_bevy_style, extract_background_color, extract_font_size,
-    extract_text_color,
+    apply_white_space, collapse_white_space, compute_element_styles,
+    compute_element_styles_in_state, compute_element_styles_with_ancestors,
+    convert_css_to_bevy_style, extract_background_color, extract_background_layers,
+    extract_border_color, extract_box_shadow, extract_calc_styles, extract_font, extract_font_size,
+    extract_line_height, extract_object_fit, extract_opacity, extract_outline, extract_text_color,
+    extract_text_ellipsis, extract_text_layout, extract_visibility, extract_z_index,
+    has_state_styles, is_display_none, is_inline_element, preserves_white_space, root_font_size,
 };
 use bevy::prelude::*;
+use bevy::text::LineHeight;
 use std::collections::HashMap;
 
 // Data structures
@@ -14,13 +24,111 @@ pub struct UIElement {
     pub tag: String,
     pub id: Option<String>,
     pub classes: Vec<String>,
+    pub attributes: HashMap<String, String>,
+    // ข้อความทั้งหมดของ inline content (รวม inline children เช่น <b>, <span>)
     pub text: String,
+    // inline content ที่ spawn เป็น Text + TextSpan แทรกระหว่าง block children
+    pub text_blocks: Vec<TextBlock>,
+    // block-level children เท่านั้น (inline children อยู่ใน text_blocks)
     pub children: Vec<UIElement>,
     pub computed_style: Node,
+    pub calc_styles: CalcStyles,
     pub background_color: BackgroundColor,
+    // background-image / border-image ตามลำดับการวาด
+    pub background_layers: Vec<BackgroundLayer>,
+    // object-fit สำหรับ <img>
+    pub object_fit: ImageFit,
+    pub border_color: BorderColor,
+    pub box_shadow: BoxShadow,
+    pub outline: Option<Outline>,
     pub text_color: Color,
     pub font_size: f32,
+    pub font: FontQuery,
+    // text-align / white-space
+    pub text_layout: TextLayout,
+    pub line_height: LineHeight,
+    // text-overflow: ellipsis
+    pub text_ellipsis: bool,
     pub border_radius: BorderRadius,
+    pub z_index: Option<ZIndex>,
+    pub global_z_index: Option<GlobalZIndex>,
+    pub visibility: Visibility,
+    pub opacity: f32,
+    // styles สำหรับ :hover / :active / :focus (ว่างถ้าไม่มี rule ที่เกี่ยวข้อง)
+    pub state_styles: Vec<StateStyle>,
+}
+
+// Elements ที่เป็น inline โดยปริยาย
+pub const INLINE_TAGS: &[&str] = &[
+    "a", "abbr", "b", "br", "cite", "code", "em", "i", "kbd", "label", "mark", "q", "s", "small",
+    "span", "strong", "sub", "sup", "u",
+];
+
+// ข้อความหนึ่งช่วงที่มี style เดียวกัน (text node ใน block หรือใน inline element)
+#[derive(Debug, Clone)]
+pub struct TextRun {
+    pub text: String,
+    // None = ใช้ color ของ block element (จึงเปลี่ยนตาม :hover ของ block ได้)
+    pub color: Option<Color>,
+    pub font_size: f32,
+    pub font: FontQuery,
+    pub line_height: LineHeight,
+}
+
+// inline content ต่อเนื่องระหว่าง block children (spawn เป็น Text หนึ่งตัว)
+#[derive(Debug, Clone)]
+pub struct TextBlock {
+    // จำนวน block children ที่อยู่ก่อนหน้า
+    pub position: usize,
+    pub runs: Vec<TextRun>,
+}
+
+// Styles of an element while it is in a given interactive state
+#[derive(Debug, Clone)]
+pub struct StateStyle {
+    pub state: ElementState,
+    pub computed_style: Node,
+    pub calc_styles: CalcStyles,
+    pub background_color: BackgroundColor,
+    pub border_color: BorderColor,
+    pub box_shadow: BoxShadow,
+    pub outline: Option<Outline>,
+    pub text_color: Color,
+    pub opacity: f32,
+}
+
+impl StateStyle {
+    fn from_properties(
+        state: ElementState,
+        properties: &HashMap<String, CssPropertyValue>,
+    ) -> Self {
+        StateStyle {
+            state,
+            computed_style: convert_css_to_bevy_style(properties),
+            calc_styles: extract_calc_styles(properties),
+            background_color: extract_background_color(properties),
+            border_color: extract_border_color(properties),
+            box_shadow: extract_box_shadow(properties),
+            outline: extract_outline(properties),
+            text_color: extract_text_color(properties),
+            opacity: extract_opacity(properties),
+        }
+    }
+
+    // คูณ alpha ของทุกสีด้วย opacity ของ element และของ ancestors
+    pub fn with_opacity(mut self, inherited: f32) -> Self {
+        let opacity = self.opacity * inherited;
+        self.background_color.0 = fade(self.background_color.0, opacity);
+        self.border_color.0 = fade(self.border_color.0, opacity);
+        for shadow in self.box_shadow.0.iter_mut() {
+            shadow.color = fade(shadow.color, opacity);
+        }
+        if let Some(outline) = &mut self.outline {
+            outline.color = fade(outline.color, opacity);
+        }
+        self.text_color = fade(self.text_color, opacity);
+        self
+    }
 }
 
 impl UIElement {
@@ -36,42 +144,112 @@ impl UIElement {
             .attr("class")
             .map(|c| c.split_whitespace().map(|s| s.to_string()).collect())
             .unwrap_or_default();
-
-        let text = element.text().collect::<String>().trim().to_string();
+        let attributes: HashMap<String, String> = value
+            .attrs()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
 
         // Compute styles
+        let root_font_size = stylesheet.as_ref().map_or(DEFAULT_FONT_SIZE, |stylesheet| {
+            root_font_size(element, stylesheet)
+        });
+        let parent_properties = parent_element_styles(element, stylesheet, root_font_size);
         let css_properties = if let Some(stylesheet) = stylesheet {
-            compute_element_styles(&tag, &id, &classes, stylesheet)
+            compute_element_styles(
+                element,
+                stylesheet,
+                Some(&parent_properties),
+                root_font_size,
+            )
         } else {
             HashMap::new()
         };
+        let (z_index, global_z_index) = extract_z_index(&css_properties);
+        let visibility = extract_visibility(&css_properties, &parent_properties);
+        let opacity = extract_opacity(&css_properties);
+        let state_styles =
+            compute_state_styles(element, stylesheet, &parent_properties, root_font_size);
 
         let computed_style = convert_css_to_bevy_style(&css_properties);
+        let calc_styles = extract_calc_styles(&css_properties);
         let background_color = extract_background_color(&css_properties);
+        let background_layers = extract_background_layers(&css_properties);
+        let object_fit = extract_object_fit(&css_properties);
+        let border_color = extract_border_color(&css_properties);
+        let box_shadow = extract_box_shadow(&css_properties);
+        let outline = extract_outline(&css_properties);
         let border_radius = extract_border_radius(&css_properties);
         let text_color = extract_text_color(&css_properties);
         let font_size = extract_font_size(&css_properties);
-        println!(
-            "element: tag:{:?}, id:{:?}, classes:{:?}\n",
-            tag, id, classes
-        );
+        let font = extract_font(&css_properties);
+        let text_layout = extract_text_layout(&css_properties);
+        let line_height = extract_line_height(&css_properties);
+        let text_ellipsis = extract_text_ellipsis(&css_properties);
+        // ข้อความทั้งหมดรวมของ descendants เป็น run เดียว
+        let text = apply_white_space(&element.text().collect::<String>(), &css_properties);
+        let text_blocks = if text.is_empty() {
+            Vec::new()
+        } else {
+            vec![TextBlock {
+                position: 0,
+                runs: vec![TextRun::new(text.clone(), None, &css_properties)],
+            }]
+        };
+        debug!("element: tag:{:?}, id:{:?}, classes:{:?}", tag, id, classes);
         UIElement {
             tag,
             id,
             classes,
+            attributes,
             text,
+            text_blocks,
             children: Vec::new(),
             computed_style,
+            calc_styles,
             background_color,
+            background_layers,
+            object_fit,
+            border_color,
+            box_shadow,
+            outline,
             text_color,
             font_size,
+            font,
+            text_layout,
+            line_height,
+            text_ellipsis,
             border_radius,
+            z_index,
+            global_z_index,
+            visibility,
+            opacity,
+            state_styles,
         }
     }
 
+    // tabindex attribute ก่อน แล้วจึง element ที่ focus ได้โดยปริยาย
+    pub fn tab_index(&self) -> Option<i32> {
+        if let Some(tab_index) = self.attributes.get("tabindex") {
+            return tab_index.trim().parse().ok();
+        }
+        is_focusable(&self.tag, self.attributes.contains_key("href")).then_some(0)
+    }
+
+    // root_font_size: font-size ของ root element (คำนวณครั้งเดียวด้วย utils::root_font_size)
     pub fn from_html_element_with_children(
         element: &scraper::ElementRef,
         stylesheet: &Option<Box<CssStyleSheet>>,
+        root_font_size: f32,
+    ) -> Self {
+        let parent_properties = parent_element_styles(element, stylesheet, root_font_size);
+        Self::from_html_element_with_parent(element, stylesheet, &parent_properties, root_font_size)
+    }
+
+    fn from_html_element_with_parent(
+        element: &scraper::ElementRef,
+        stylesheet: &Option<Box<CssStyleSheet>>,
+        parent_properties: &HashMap<String, CssPropertyValue>,
+        root_font_size: f32,
     ) -> Self {
         let value = element.value();
 
@@ -81,44 +259,96 @@ impl UIElement {
             .attr("class")
             .map(|c| c.split_whitespace().map(|s| s.to_string()).collect())
             .unwrap_or_default();
-
-        // สำหรับ text เฉพาะของ element นี้ (ไม่รวม children)
-        let text = element
-            .children()
-            .filter(|child| child.value().is_text())
-            .map(|text_node| text_node.value().as_text().unwrap().as_ref())
-            .collect::<Vec<&str>>()
-            .join("")
-            .trim()
-            .to_string();
+        let attributes: HashMap<String, String> = value
+            .attrs()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
 
         // Compute styles
         let css_properties = if let Some(stylesheet) = stylesheet {
-            compute_element_styles(&tag, &id, &classes, stylesheet)
+            compute_element_styles(element, stylesheet, Some(parent_properties), root_font_size)
         } else {
             HashMap::new()
         };
+        let (z_index, global_z_index) = extract_z_index(&css_properties);
+        let visibility = extract_visibility(&css_properties, parent_properties);
+        let opacity = extract_opacity(&css_properties);
+        let state_styles =
+            compute_state_styles(element, stylesheet, parent_properties, root_font_size);
 
-        println!("css_properties: {:?} ", &id);
         let computed_style = convert_css_to_bevy_style(&css_properties);
+        let calc_styles = extract_calc_styles(&css_properties);
         let background_color = extract_background_color(&css_properties);
+        let background_layers = extract_background_layers(&css_properties);
+        let object_fit = extract_object_fit(&css_properties);
+        let border_color = extract_border_color(&css_properties);
+        let box_shadow = extract_box_shadow(&css_properties);
+        let outline = extract_outline(&css_properties);
         let text_color = extract_text_color(&css_properties);
         let font_size = extract_font_size(&css_properties);
+        let font = extract_font(&css_properties);
+        let text_layout = extract_text_layout(&css_properties);
+        let line_height = extract_line_height(&css_properties);
+        let text_ellipsis = extract_text_ellipsis(&css_properties);
         let border_radius = extract_border_radius(&css_properties);
-        // สร้าง children แบบ recursive
-        let children: Vec<UIElement> = element
-            .children()
-            .filter(|child| child.value().is_element())
-            .map(|child_element| {
-                UIElement::from_html_element_with_children(
-                    &scraper::ElementRef::wrap(child_element).unwrap(),
+
+        // สร้าง block children แบบ recursive ส่วน text และ inline children รวมเป็น text blocks
+        let mut children: Vec<UIElement> = Vec::new();
+        let mut inline = InlineContent {
+            root_font_size,
+            ..default()
+        };
+        for child in element.children() {
+            if let Some(text) = child.value().as_text() {
+                inline.push_text(children.len(), text, None, &css_properties);
+                continue;
+            }
+            let Some(child_element) = scraper::ElementRef::wrap(child) else {
+                continue;
+            };
+            let child_tag = child_element.value().name();
+            let child_properties = match stylesheet {
+                Some(stylesheet) if INLINE_TAGS.contains(&child_tag) => compute_element_styles(
+                    &child_element,
                     stylesheet,
-                )
-            })
+                    Some(&css_properties),
+                    root_font_size,
+                ),
+                _ => HashMap::new(),
+            };
+            if is_inline_element(child_tag, &child_properties)
+                && !is_interactive(&child_element, stylesheet)
+            {
+                inline.push_element(
+                    children.len(),
+                    &child_element,
+                    stylesheet,
+                    &child_properties,
+                    text_color,
+                    1.0,
+                );
+            } else {
+                children.push(UIElement::from_html_element_with_parent(
+                    &child_element,
+                    stylesheet,
+                    &css_properties,
+                    root_font_size,
+                ));
+            }
+        }
+        // <style> / <script> ไม่แสดงข้อความ
+        let text_blocks = match tag.as_str() {
+            "script" | "style" | "template" => Vec::new(),
+            _ => inline.finish(),
+        };
+        let text = text_blocks
+            .iter()
+            .flat_map(|block| &block.runs)
+            .map(|run| run.text.as_str())
             .collect();
 
-        println!(
-            "element: tag:{:?}, id:{:?}, classes:{:?}, children_count:{}\n",
+        debug!(
+            "element: tag:{:?}, id:{:?}, classes:{:?}, children_count:{}",
             tag,
             id,
             classes,
@@ -129,13 +359,249 @@ impl UIElement {
             tag,
             id,
             classes,
+            attributes,
             text,
+            text_blocks,
             children,
             computed_style,
+            calc_styles,
             background_color,
+            background_layers,
+            object_fit,
+            border_color,
+            box_shadow,
+            outline,
             text_color,
             font_size,
+            font,
+            text_layout,
+            line_height,
+            text_ellipsis,
             border_radius,
+            z_index,
+            global_z_index,
+            visibility,
+            opacity,
+            state_styles,
+        }
+    }
+}
+
+pub fn fade(color: Color, opacity: f32) -> Color {
+    color.with_alpha(color.alpha() * opacity)
+}
+
+impl TextRun {
+    fn new(
+        text: String,
+        color: Option<Color>,
+        properties: &HashMap<String, CssPropertyValue>,
+    ) -> Self {
+        TextRun {
+            text,
+            color,
+            font_size: extract_font_size(properties),
+            font: extract_font(properties),
+            line_height: extract_line_height(properties),
+        }
+    }
+}
+
+// รวม text nodes ตามลำดับเป็น TextBlock และยุบช่องว่างข้ามขอบของ runs เหมือน browser
+#[derive(Default)]
+struct InlineContent {
+    blocks: Vec<TextBlock>,
+    // ข้อความก่อนหน้าจบด้วยช่องว่างที่ยุบได้หรือขึ้นบรรทัดใหม่ → ช่องว่างนำหน้าของข้อความถัดไปหายไป
+    after_space: bool,
+    // สำหรับ rem ของ inline elements
+    root_font_size: f32,
+}
+
+impl InlineContent {
+    // text block ที่ตำแหน่งนี้ (block children คั่นอยู่ = เริ่ม block ใหม่)
+    fn block(&mut self, position: usize) -> &mut TextBlock {
+        if self
+            .blocks
+            .last()
+            .is_none_or(|block| block.position != position)
+        {
+            self.trim_trailing_space();
+            self.blocks.push(TextBlock {
+                position,
+                runs: Vec::new(),
+            });
+            self.after_space = true;
+        }
+        self.blocks.last_mut().unwrap()
+    }
+
+    fn push_text(
+        &mut self,
+        position: usize,
+        text: &str,
+        color: Option<Color>,
+        properties: &HashMap<String, CssPropertyValue>,
+    ) {
+        self.block(position);
+        let collapsible = !preserves_white_space(properties);
+        let mut text = collapse_white_space(text, properties);
+        if collapsible && self.after_space {
+            text = text.trim_start_matches(' ').to_string();
+        }
+        if text.is_empty() {
+            return;
+        }
+        self.after_space = (collapsible && text.ends_with(' ')) || text.ends_with('\n');
+        let run = TextRun::new(text, color, properties);
+        self.block(position).runs.push(run);
+    }
+
+    // <br> และ inline element ที่ซ้อนกัน (ทุก descendant อยู่ใน text block เดียวกัน)
+    fn push_element(
+        &mut self,
+        position: usize,
+        element: &scraper::ElementRef,
+        stylesheet: &Option<Box<CssStyleSheet>>,
+        properties: &HashMap<String, CssPropertyValue>,
+        block_color: Color,
+        opacity: f32,
+    ) {
+        if element.value().name() == "br" {
+            self.block(position);
+            self.trim_trailing_space();
+            let line_break = TextRun::new("\n".to_string(), None, properties);
+            self.block(position).runs.push(line_break);
+            self.after_space = true;
+            return;
+        }
+
+        // สีเดียวกับ block = None เพื่อให้เปลี่ยนตาม :hover ของ block
+        let opacity = opacity * extract_opacity(properties);
+        let color = extract_text_color(properties);
+        let color = if extract_visibility(properties, &HashMap::new()) == Visibility::Hidden {
+            Some(Color::NONE)
+        } else if opacity < 1.0 || color != block_color {
+            Some(fade(color, opacity))
+        } else {
+            None
+        };
+        for child in element.children() {
+            if let Some(text) = child.value().as_text() {
+                self.push_text(position, text, color, properties);
+            } else if let Some(child_element) = scraper::ElementRef::wrap(child) {
+                let child_properties = match stylesheet {
+                    Some(stylesheet) => compute_element_styles(
+                        &child_element,
+                        stylesheet,
+                        Some(properties),
+                        self.root_font_size,
+                    ),
+                    None => HashMap::new(),
+                };
+                if !is_display_none(&child_properties) {
+                    self.push_element(
+                        position,
+                        &child_element,
+                        stylesheet,
+                        &child_properties,
+                        block_color,
+                        opacity,
+                    );
+                }
+            }
+        }
+    }
+
+    // ช่องว่างท้ายบรรทัดหายไป และไม่เก็บ text block ที่ว่าง
+    fn trim_trailing_space(&mut self) {
+        let Some(block) = self.blocks.last_mut() else {
+            return;
+        };
+        if self.after_space
+            && let Some(run) = block.runs.last_mut()
+        {
+            run.text.truncate(run.text.trim_end_matches(' ').len());
+            if run.text.is_empty() {
+                block.runs.pop();
+            }
+        }
+        if block.runs.is_empty() {
+            self.blocks.pop();
         }
     }
+
+    fn finish(mut self) -> Vec<TextBlock> {
+        self.trim_trailing_space();
+        self.blocks
+    }
+}
+
+// element ที่ focus ได้โดยปริยาย (ไม่นับ tabindex)
+fn is_focusable(tag: &str, has_href: bool) -> bool {
+    match tag {
+        "button" | "input" | "select" | "textarea" => true,
+        "a" => has_href,
+        _ => false,
+    }
+}
+
+// element ที่ focus หรือมี :hover/:active/:focus ต้องเป็น node ของตัวเอง (TabIndex, Interaction)
+// จึงรวมเป็น text run ไม่ได้ — รวมถึง inline element ที่มี element แบบนี้อยู่ข้างใน
+fn is_interactive(element: &scraper::ElementRef, stylesheet: &Option<Box<CssStyleSheet>>) -> bool {
+    element
+        .descendants()
+        .filter_map(scraper::ElementRef::wrap)
+        .any(|element| {
+            let value = element.value();
+            is_focusable(value.name(), value.attr("href").is_some())
+                || value.attr("tabindex").is_some()
+                || stylesheet
+                    .as_ref()
+                    .is_some_and(|stylesheet| has_state_styles(&element, stylesheet))
+        })
+}
+
+// styles ที่ inherit มาจาก ancestors (เช่น body, html)
+fn parent_element_styles(
+    element: &scraper::ElementRef,
+    stylesheet: &Option<Box<CssStyleSheet>>,
+    root_font_size: f32,
+) -> HashMap<String, CssPropertyValue> {
+    match (
+        stylesheet,
+        element.parent().and_then(scraper::ElementRef::wrap),
+    ) {
+        (Some(stylesheet), Some(parent)) => {
+            compute_element_styles_with_ancestors(&parent, stylesheet, root_font_size)
+        }
+        _ => HashMap::new(),
+    }
+}
+
+fn compute_state_styles(
+    element: &scraper::ElementRef,
+    stylesheet: &Option<Box<CssStyleSheet>>,
+    parent_properties: &HashMap<String, CssPropertyValue>,
+    root_font_size: f32,
+) -> Vec<StateStyle> {
+    let Some(stylesheet) = stylesheet else {
+        return Vec::new();
+    };
+    if !has_state_styles(element, stylesheet) {
+        return Vec::new();
+    }
+
+    ElementState::variants()
+        .into_iter()
+        .map(|state| {
+            let properties = compute_element_styles_in_state(
+                element,
+                stylesheet,
+                Some(parent_properties),
+                state,
+                root_font_size,
+            );
+            StateStyle::from_properties(state, &properties)
+        })
+        .collect()
 }
@@ -0,0 +1,133 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+// font ที่ element ขอจาก font-family / font-weight / font-style
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontQuery {
+    // ตามลำดับใน font-family (family แรกที่มีใน registry ถูกใช้)
+    pub families: Vec<String>,
+    pub weight: f32,
+    pub italic: bool,
+}
+
+impl Default for FontQuery {
+    fn default() -> Self {
+        Self {
+            families: Vec::new(),
+            weight: 400.0,
+            italic: false,
+        }
+    }
+}
+
+// font file หนึ่งไฟล์ของ family (path เทียบกับโฟลเดอร์ assets)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontFace {
+    pub path: String,
+    pub weight: f32,
+    pub italic: bool,
+}
+
+// font files ที่ font-family อ้างถึงได้ (ชื่อ family ไม่สนตัวพิมพ์เล็กใหญ่)
+#[derive(Debug, Clone)]
+pub struct FontRegistry {
+    families: HashMap<String, Vec<FontFace>>,
+    // ใช้เมื่อไม่มี family ไหนใน font-family ที่ register ไว้ (รวมถึง generic เช่น sans-serif)
+    fallback: Vec<String>,
+}
+
+impl Default for FontRegistry {
+    // Prompt ใน assets/fonts เป็น fallback
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        for (file, weight, italic) in [
+            ("Regular", 400.0, false),
+            ("Italic", 400.0, true),
+            ("Medium", 500.0, false),
+            ("MediumItalic", 500.0, true),
+            ("Bold", 700.0, false),
+            ("BoldItalic", 700.0, true),
+        ] {
+            registry.register("Prompt", format!("fonts/Prompt-{file}.ttf"), weight, italic);
+        }
+        registry.set_fallback(["Prompt"]);
+        registry
+    }
+}
+
+impl FontRegistry {
+    pub fn empty() -> Self {
+        Self {
+            families: HashMap::new(),
+            fallback: Vec::new(),
+        }
+    }
+
+    // register ซ้ำด้วย weight / style เดิมจะแทนที่ไฟล์เดิม
+    pub fn register(&mut self, family: &str, path: impl Into<String>, weight: f32, italic: bool) {
+        let faces = self.families.entry(family.to_lowercase()).or_default();
+        faces.retain(|face| face.weight != weight || face.italic != italic);
+        faces.push(FontFace {
+            path: path.into(),
+            weight,
+            italic,
+        });
+    }
+
+    pub fn set_fallback<S: Into<String>>(&mut self, families: impl IntoIterator<Item = S>) {
+        self.fallback = families.into_iter().map(Into::into).collect();
+    }
+
+    pub fn faces(&self, family: &str) -> &[FontFace] {
+        self.families
+            .get(&family.to_lowercase())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    // None = ไม่มี family ที่ใช้ได้ (ใช้ font เริ่มต้นของ Bevy)
+    pub fn resolve(&self, query: &FontQuery) -> Option<&FontFace> {
+        let faces = query
+            .families
+            .iter()
+            .chain(&self.fallback)
+            .map(|family| self.faces(family))
+            .find(|faces| !faces.is_empty())?;
+        match_face(faces, query.weight, query.italic)
+    }
+}
+
+// CSS font matching: เลือก style ก่อน แล้วจึง weight ที่ใกล้ที่สุดตามทิศทางที่ spec กำหนด
+fn match_face(faces: &[FontFace], weight: f32, italic: bool) -> Option<&FontFace> {
+    // ไม่มี italic ก็ใช้ตัวตรงแทน (และกลับกัน)
+    let styled = faces.iter().any(|face| face.italic == italic);
+    // (ลำดับกลุ่ม, ระยะห่าง) — น้อยกว่า = ตรงกว่า
+    let rank = |face: &FontFace| {
+        let candidate = face.weight;
+        let tier = if (400.0..=500.0).contains(&weight) {
+            // 400-500: หนักขึ้นถึง 500 ก่อน, แล้วเบากว่า, แล้วหนักกว่า 500
+            if candidate >= weight && candidate <= 500.0 {
+                0
+            } else if candidate < weight {
+                1
+            } else {
+                2
+            }
+        } else if weight < 400.0 {
+            // เบากว่า 400: หาตัวที่เบากว่าก่อน
+            if candidate <= weight { 0 } else { 1 }
+        } else if candidate >= weight {
+            0
+        } else {
+            1
+        };
+        (tier, (candidate - weight).abs())
+    };
+    faces
+        .iter()
+        .filter(|face| !styled || face.italic == italic)
+        .min_by(|a, b| {
+            let (a, b) = (rank(a), rank(b));
+            a.0.cmp(&b.0)
+                .then(a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+        })
+}
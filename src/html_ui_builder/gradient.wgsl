@@ -0,0 +1,73 @@
+// linear-gradient() / radial-gradient() สำหรับ GradientMaterial
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+struct GradientParams {
+    size: vec2<f32>,
+    kind: u32,
+    repeating: u32,
+    geometry: vec4<f32>,
+    count: u32,
+    colors: array<vec4<f32>, 8>,
+    offsets: array<vec4<f32>, 2>,
+}
+
+@group(1) @binding(0) var<uniform> params: GradientParams;
+
+fn stop_offset(index: u32) -> f32 {
+    return params.offsets[index / 4u][index % 4u];
+}
+
+fn premultiply(color: vec4<f32>) -> vec4<f32> {
+    return vec4(color.rgb * color.a, color.a);
+}
+
+fn srgb_to_linear(value: vec3<f32>) -> vec3<f32> {
+    let low = value / 12.92;
+    let high = pow((value + 0.055) / 1.055, vec3(2.4));
+    return select(high, low, value <= vec3(0.04045));
+}
+
+// เหมือน sd_rounded_box ใน bevy_ui
+fn sd_rounded_box(point: vec2<f32>, size: vec2<f32>, corner_radii: vec4<f32>) -> f32 {
+    let rs = select(corner_radii.xy, corner_radii.wz, 0.0 < point.y);
+    let radius = select(rs.x, rs.y, 0.0 < point.x);
+    let q = abs(point) - 0.5 * size + radius;
+    return length(max(q, vec2(0.0))) + min(max(q.x, q.y), 0.0) - radius;
+}
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    // px เทียบกับกลางกล่อง
+    let point = (in.uv - 0.5) * params.size;
+    var t: f32;
+    if params.kind == 0u {
+        t = dot(point, params.geometry.xy) / params.geometry.z + 0.5;
+    } else {
+        t = length((point - params.geometry.xy) / params.geometry.zw);
+    }
+
+    let last = params.count - 1u;
+    if params.repeating == 1u {
+        let first = stop_offset(0u);
+        let span = stop_offset(last) - first;
+        if span > 0.0 {
+            t = first + fract((t - first) / span) * span;
+        }
+    }
+
+    // interpolate แบบ premultiplied alpha เหมือน browser
+    var color = premultiply(params.colors[0]);
+    for (var i = 1u; i <= last; i++) {
+        let start = stop_offset(i - 1u);
+        if t > start {
+            let amount = clamp((t - start) / max(stop_offset(i) - start, 1e-5), 0.0, 1.0);
+            color = mix(premultiply(params.colors[i - 1u]), premultiply(params.colors[i]), amount);
+        }
+    }
+    let rgb = select(vec3(0.0), color.rgb / color.a, color.a > 0.0);
+
+    // ตัดมุมตาม border-radius
+    let edge = sd_rounded_box((in.uv - 0.5) * in.size, in.size, in.border_radius);
+    let coverage = clamp(0.5 - edge, 0.0, 1.0);
+    return vec4(srgb_to_linear(rgb), color.a * coverage);
+}
@@ -0,0 +1,152 @@
+use super::calc::CalcStyles;
+use super::element::StateStyle;
+use super::selector::ElementState;
+use super::text::InheritedTextColor;
+use bevy::input_focus::tab_navigation::TabIndex;
+use bevy::input_focus::{InputFocus, InputFocusVisible};
+use bevy::prelude::*;
+
+// ใช้แทน outline-style: none บน entity ที่ state อื่นมี outline
+pub(crate) const NO_OUTLINE: Outline = Outline::new(Val::ZERO, Val::ZERO, Color::NONE);
+
+// Styles ของ element ที่มี rule :hover / :active / :focus
+#[derive(Component, Debug, Clone)]
+pub struct InteractionStyles {
+    pub base: StateStyle,
+    pub states: Vec<StateStyle>,
+    pub current: ElementState,
+}
+
+impl InteractionStyles {
+    pub fn new(base: StateStyle, states: Vec<StateStyle>) -> Self {
+        Self {
+            base,
+            states,
+            current: ElementState::default(),
+        }
+    }
+
+    pub fn style_for(&self, state: ElementState) -> &StateStyle {
+        self.states
+            .iter()
+            .find(|style| style.state == state)
+            .unwrap_or(&self.base)
+    }
+}
+
+// คลิกที่ element ที่ focus ได้หรือมี interaction styles จะได้ focus (เหมือน browser และ Tab)
+#[allow(clippy::type_complexity)]
+pub fn focus_on_press(
+    interactions: Query<
+        (Entity, &Interaction),
+        (
+            Changed<Interaction>,
+            Or<(With<TabIndex>, With<InteractionStyles>)>,
+        ),
+    >,
+    mut input_focus: ResMut<InputFocus>,
+    mut focus_visible: ResMut<InputFocusVisible>,
+) {
+    for (entity, interaction) in &interactions {
+        if *interaction == Interaction::Pressed {
+            input_focus.set(entity);
+            focus_visible.0 = false;
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn apply_interaction_styles(
+    input_focus: Res<InputFocus>,
+    focus_visible: Res<InputFocusVisible>,
+    mut nodes: Query<(
+        Entity,
+        &Interaction,
+        &mut InteractionStyles,
+        &mut Node,
+        &mut BackgroundColor,
+        &mut BorderColor,
+        Option<&mut BoxShadow>,
+        Option<&mut Outline>,
+        Option<&mut CalcStyles>,
+    )>,
+    children: Query<&Children>,
+    text_blocks: Query<(), With<Text>>,
+    inherited: Query<(), With<InheritedTextColor>>,
+    mut text_colors: Query<&mut TextColor>,
+) {
+    for (
+        entity,
+        interaction,
+        mut styles,
+        mut node,
+        mut background_color,
+        mut border_color,
+        box_shadow,
+        outline,
+        calc_styles,
+    ) in &mut nodes
+    {
+        let focused = input_focus.0 == Some(entity);
+        let state = ElementState {
+            hover: matches!(interaction, Interaction::Hovered | Interaction::Pressed),
+            active: *interaction == Interaction::Pressed,
+            focus: focused,
+            focus_visible: focused && focus_visible.0,
+        };
+        if state == styles.current {
+            continue;
+        }
+        styles.current = state;
+
+        let style = styles.style_for(state);
+        *node = style.computed_style.clone();
+        *background_color = style.background_color;
+        *border_color = style.border_color;
+        if let Some(mut box_shadow) = box_shadow {
+            *box_shadow = style.box_shadow.clone();
+        }
+        if let Some(mut outline) = outline {
+            *outline = style.outline.unwrap_or(NO_OUTLINE);
+        }
+        if let Some(mut calc_styles) = calc_styles {
+            *calc_styles = style.calc_styles.clone();
+        }
+        let color = style.text_color;
+        set_text_color(
+            entity,
+            color,
+            &children,
+            &text_blocks,
+            &inherited,
+            &mut text_colors,
+        );
+    }
+}
+
+// text ของ element และของ descendants ที่สืบทอด color (หยุดที่ element / span ที่กำหนด color เอง)
+fn set_text_color(
+    entity: Entity,
+    color: Color,
+    children: &Query<&Children>,
+    text_blocks: &Query<(), With<Text>>,
+    inherited: &Query<(), With<InheritedTextColor>>,
+    text_colors: &mut Query<&mut TextColor>,
+) {
+    for child in children.get(entity).into_iter().flatten() {
+        if text_blocks.contains(*child) {
+            if let Ok(mut text_color) = text_colors.get_mut(*child) {
+                text_color.0 = color;
+            }
+            for span in children.get(*child).into_iter().flatten() {
+                if inherited.contains(*span)
+                    && let Ok(mut text_color) = text_colors.get_mut(*span)
+                {
+                    text_color.0 = color;
+                }
+            }
+        } else if inherited.contains(*child) {
+            set_text_color(*child, color, children, text_blocks, inherited, text_colors);
+        }
+    }
+}
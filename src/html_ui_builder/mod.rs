@@ -1,8 +1,22 @@
+pub mod background;
 pub mod builder;
+pub mod calc;
 pub mod css;
 pub mod element;
+pub mod font;
+pub mod interaction;
+pub mod plugin;
+pub mod scroll;
+pub mod selector;
+pub mod text;
 pub mod utils;
 
+pub use background::GradientMaterial;
 pub use builder::HtmlCssUIBuilder;
+pub use calc::CalcStyles;
 pub use css::{CssPropertyValue, CssRule_, CssStyleSheet};
-pub use element::UIElement;
+pub use element::{StateStyle, UIElement};
+pub use font::{FontQuery, FontRegistry};
+pub use interaction::InteractionStyles;
+pub use plugin::HtmlCssUIPlugin;
+pub use selector::Selector;
@@ -0,0 +1,46 @@
+use super::background::{GradientMaterial, fit_background_images, update_gradient_materials};
+use super::calc::apply_calc_styles;
+use super::interaction::{apply_interaction_styles, focus_on_press};
+use super::scroll::{scroll_on_drag, scroll_on_wheel};
+use super::text::truncate_text_with_ellipsis;
+use bevy::asset::embedded_asset;
+use bevy::input_focus::tab_navigation::TabNavigationPlugin;
+use bevy::input_focus::{InputDispatchPlugin, InputFocus, InputFocusVisible};
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+
+// Runtime systems for UI spawned by HtmlCssUIBuilder
+pub struct HtmlCssUIPlugin;
+
+impl Plugin for HtmlCssUIPlugin {
+    fn build(&self, app: &mut App) {
+        // Tab / Shift+Tab ย้าย focus และเปิด :focus-visible
+        if !app.is_plugin_added::<InputDispatchPlugin>() {
+            app.add_plugins(InputDispatchPlugin);
+        }
+        if !app.is_plugin_added::<TabNavigationPlugin>() {
+            app.add_plugins(TabNavigationPlugin);
+        }
+        // shader ของ linear-gradient() / radial-gradient()
+        embedded_asset!(app, "gradient.wgsl");
+        app.add_plugins(UiMaterialPlugin::<GradientMaterial>::default());
+
+        app.init_resource::<InputFocus>()
+            .init_resource::<InputFocusVisible>()
+            .add_systems(
+                Update,
+                (focus_on_press, apply_interaction_styles, apply_calc_styles).chain(),
+            )
+            // overflow: scroll / auto เลื่อนด้วยล้อเมาส์หรือการลาก
+            .add_systems(Update, (scroll_on_wheel, scroll_on_drag))
+            .add_systems(
+                PostUpdate,
+                (fit_background_images, update_gradient_materials).after(UiSystem::Layout),
+            )
+            // text-overflow: ellipsis ต้องใช้ขนาดของ text หลัง layout
+            .add_systems(
+                PostUpdate,
+                truncate_text_with_ellipsis.after(UiSystem::PostLayout),
+            );
+    }
+}
@@ -0,0 +1,114 @@
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use bevy::window::PrimaryWindow;
+
+// px ต่อหนึ่งบรรทัดของล้อเมาส์ (MouseScrollUnit::Line)
+pub const SCROLL_LINE_HEIGHT: f32 = 20.0;
+
+type ScrollContainer = (
+    Entity,
+    &'static Node,
+    &'static ComputedNode,
+    &'static RelativeCursorPosition,
+    &'static mut ScrollPosition,
+);
+
+// ล้อเมาส์ scroll container ในสุดที่อยู่ใต้ cursor และยังเลื่อนต่อได้ (ไม่ได้ก็ส่งต่อให้ container ชั้นนอก)
+pub fn scroll_on_wheel(
+    mut wheel_events: EventReader<MouseWheel>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut containers: Query<ScrollContainer>,
+) {
+    for event in wheel_events.read() {
+        let mut delta = match event.unit {
+            MouseScrollUnit::Line => Vec2::new(event.x, event.y) * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => Vec2::new(event.x, event.y),
+        };
+        // Shift + ล้อเมาส์ = เลื่อนแนวนอนเหมือน browser
+        if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            delta = Vec2::new(delta.y, delta.x);
+        }
+        // หมุนล้อขึ้น (y > 0) = เลื่อนกลับไปด้านบน
+        for entity in hovered_containers(&containers) {
+            let Ok((_, node, computed, _, mut position)) = containers.get_mut(entity) else {
+                continue;
+            };
+            if let Some(scrolled) = scroll_by(&position, -delta, max_scroll(node, computed)) {
+                *position = scrolled;
+                break;
+            }
+        }
+    }
+}
+
+// กดค้างแล้วลากบน container เพื่อ scroll (เช่นรายการบนจอสัมผัส)
+pub fn scroll_on_drag(
+    mouse: Res<ButtonInput<MouseButton>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut containers: Query<ScrollContainer>,
+    // container ที่กำลังลากและตำแหน่ง cursor ล่าสุด
+    mut drag: Local<Option<(Entity, Vec2)>>,
+) {
+    let cursor = windows.single().ok().and_then(Window::cursor_position);
+    let (Some(cursor), true) = (cursor, mouse.pressed(MouseButton::Left)) else {
+        *drag = None;
+        return;
+    };
+    if mouse.just_pressed(MouseButton::Left) {
+        *drag = hovered_containers(&containers)
+            .first()
+            .map(|entity| (*entity, cursor));
+    }
+    let Some((entity, last_cursor)) = drag.as_mut() else {
+        return;
+    };
+    // ลากขึ้น = เลื่อนเนื้อหาลง
+    let delta = *last_cursor - cursor;
+    *last_cursor = cursor;
+    if let Ok((_, node, computed, _, mut position)) = containers.get_mut(*entity)
+        && let Some(scrolled) = scroll_by(&position, delta, max_scroll(node, computed))
+    {
+        *position = scrolled;
+    }
+}
+
+// container ที่อยู่ใต้ cursor เรียงจากชั้นในสุด (stack index สูงสุด) ออกไป
+fn hovered_containers(containers: &Query<ScrollContainer>) -> Vec<Entity> {
+    let mut hovered: Vec<(u32, Entity)> = containers
+        .iter()
+        .filter(|(_, node, _, cursor, _)| {
+            cursor.mouse_over()
+                && (node.overflow.x == OverflowAxis::Scroll
+                    || node.overflow.y == OverflowAxis::Scroll)
+        })
+        .map(|(entity, _, computed, _, _)| (computed.stack_index(), entity))
+        .collect();
+    hovered.sort_by_key(|(stack_index, _)| std::cmp::Reverse(*stack_index));
+    hovered.into_iter().map(|(_, entity)| entity).collect()
+}
+
+// ระยะที่เลื่อนได้สูงสุด (logical px) — แกนที่ไม่ใช่ overflow: scroll เป็น 0
+fn max_scroll(node: &Node, computed: &ComputedNode) -> Vec2 {
+    let max = ((computed.content_size() - computed.size()) * computed.inverse_scale_factor())
+        .max(Vec2::ZERO);
+    Vec2::new(
+        if node.overflow.x == OverflowAxis::Scroll {
+            max.x
+        } else {
+            0.0
+        },
+        if node.overflow.y == OverflowAxis::Scroll {
+            max.y
+        } else {
+            0.0
+        },
+    )
+}
+
+// None ถ้าเลื่อนต่อไม่ได้แล้ว (ไม่แตะ ScrollPosition เพื่อไม่ให้ layout ทำงานใหม่)
+fn scroll_by(position: &ScrollPosition, delta: Vec2, max: Vec2) -> Option<ScrollPosition> {
+    let current = Vec2::new(position.offset_x, position.offset_y);
+    let next = (current + delta).clamp(Vec2::ZERO, max);
+    (next != current).then(|| ScrollPosition::from(next))
+}
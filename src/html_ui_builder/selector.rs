@@ -0,0 +1,822 @@
+use bevy::log::warn;
+use scraper::ElementRef;
+
+// Parsed CSS selector (one entry of a selector list), e.g. `div.card > p`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selector {
+    // compound selectors from left to right
+    pub compounds: Vec<CompoundSelector>,
+    // combinators[i] links compounds[i] and compounds[i + 1]
+    pub combinators: Vec<Combinator>,
+}
+
+// (id selectors, class-like selectors, type selectors) — compared lexicographically
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Specificity(pub u32, pub u32, pub u32);
+
+impl std::ops::Add for Specificity {
+    type Output = Specificity;
+
+    fn add(self, other: Specificity) -> Specificity {
+        Specificity(self.0 + other.0, self.1 + other.1, self.2 + other.2)
+    }
+}
+
+// Interactive state of an element for :hover, :active, :focus and :focus-visible
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ElementState {
+    pub hover: bool,
+    pub active: bool,
+    pub focus: bool,
+    pub focus_visible: bool,
+}
+
+impl ElementState {
+    pub const ALL: ElementState = ElementState {
+        hover: true,
+        active: true,
+        focus: true,
+        focus_visible: true,
+    };
+
+    // ทุก state ที่เป็นไปได้ (ยกเว้น default) — active ต้อง hover ด้วย, focus-visible ต้อง focus ด้วย
+    pub fn variants() -> Vec<ElementState> {
+        let mut variants = Vec::new();
+        for (hover, active) in [(false, false), (true, false), (true, true)] {
+            for (focus, focus_visible) in [(false, false), (true, false), (true, true)] {
+                let state = ElementState {
+                    hover,
+                    active,
+                    focus,
+                    focus_visible,
+                };
+                if state != ElementState::default() {
+                    variants.push(state);
+                }
+            }
+        }
+        variants
+    }
+
+    fn contains(&self, pseudo_class: PseudoState) -> bool {
+        match pseudo_class {
+            PseudoState::Hover => self.hover,
+            PseudoState::Active => self.active,
+            PseudoState::Focus => self.focus,
+            PseudoState::FocusVisible => self.focus_visible,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PseudoState {
+    Hover,
+    Active,
+    Focus,
+    FocusVisible,
+}
+
+// `[name]`, `[name="value"]`, `[name^="value" i]`, ...
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeSelector {
+    pub name: String,
+    pub operation: Option<(AttributeOperator, String)>,
+    pub case_insensitive: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeOperator {
+    Equals,    // =
+    Includes,  // ~=
+    DashMatch, // |=
+    Prefix,    // ^=
+    Suffix,    // $=
+    Substring, // *=
+}
+
+impl AttributeSelector {
+    // argument คือข้อความระหว่าง [ และ ]
+    fn parse(argument: &str) -> Option<Self> {
+        let chars: Vec<char> = argument.trim().chars().collect();
+        let (name, mut pos) = read_ident(&chars, 0);
+        if name.is_empty() {
+            return None;
+        }
+        let name = name.to_ascii_lowercase();
+        skip_whitespace(&chars, &mut pos);
+        if pos >= chars.len() {
+            return Some(AttributeSelector {
+                name,
+                operation: None,
+                case_insensitive: false,
+            });
+        }
+
+        let operator = match chars[pos] {
+            '=' => AttributeOperator::Equals,
+            '~' => AttributeOperator::Includes,
+            '|' => AttributeOperator::DashMatch,
+            '^' => AttributeOperator::Prefix,
+            '$' => AttributeOperator::Suffix,
+            '*' => AttributeOperator::Substring,
+            _ => return None,
+        };
+        pos += if operator == AttributeOperator::Equals {
+            1
+        } else {
+            2
+        };
+        if operator != AttributeOperator::Equals && chars.get(pos - 1) != Some(&'=') {
+            return None;
+        }
+        skip_whitespace(&chars, &mut pos);
+
+        let value = match chars.get(pos) {
+            Some(&quote) if quote == '"' || quote == '\'' => {
+                let mut value = String::new();
+                pos += 1;
+                while pos < chars.len() && chars[pos] != quote {
+                    if chars[pos] == '\\' && pos + 1 < chars.len() {
+                        pos += 1;
+                    }
+                    value.push(chars[pos]);
+                    pos += 1;
+                }
+                pos += 1;
+                value
+            }
+            _ => {
+                let (value, next) = read_ident(&chars, pos);
+                pos = next;
+                value
+            }
+        };
+        skip_whitespace(&chars, &mut pos);
+
+        let case_insensitive = match chars.get(pos) {
+            None => false,
+            Some('i') | Some('I') => true,
+            Some('s') | Some('S') => false,
+            _ => return None,
+        };
+
+        Some(AttributeSelector {
+            name,
+            operation: Some((operator, value)),
+            case_insensitive,
+        })
+    }
+
+    fn matches(&self, element: &ElementRef) -> bool {
+        let Some(actual) = element.value().attr(&self.name) else {
+            return false;
+        };
+        let Some((operator, expected)) = &self.operation else {
+            return true;
+        };
+
+        let (actual, expected) = if self.case_insensitive {
+            (actual.to_lowercase(), expected.to_lowercase())
+        } else {
+            (actual.to_string(), expected.clone())
+        };
+        match operator {
+            AttributeOperator::Equals => actual == expected,
+            AttributeOperator::Includes => actual.split_whitespace().any(|word| word == expected),
+            AttributeOperator::DashMatch => {
+                actual == expected || actual.starts_with(&format!("{}-", expected))
+            }
+            // ค่าว่างไม่ match สำหรับ ^= $= *= ตาม spec
+            AttributeOperator::Prefix => !expected.is_empty() && actual.starts_with(&expected),
+            AttributeOperator::Suffix => !expected.is_empty() && actual.ends_with(&expected),
+            AttributeOperator::Substring => !expected.is_empty() && actual.contains(&expected),
+        }
+    }
+}
+
+// Tree-structural and logical pseudo-classes
+#[derive(Debug, Clone, PartialEq)]
+pub enum PseudoClass {
+    Root,
+    Empty,
+    FirstChild,
+    LastChild,
+    OnlyChild,
+    FirstOfType,
+    LastOfType,
+    OnlyOfType,
+    NthChild(Nth),
+    NthLastChild(Nth),
+    NthOfType(Nth),
+    NthLastOfType(Nth),
+    Not(Vec<Selector>),
+    Is(Vec<Selector>),
+    Where(Vec<Selector>),
+}
+
+// `an+b` of :nth-child() and friends (index เริ่มที่ 1)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nth {
+    pub a: i32,
+    pub b: i32,
+}
+
+impl Nth {
+    pub fn parse(input: &str) -> Option<Self> {
+        let input: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+        let input = input.to_ascii_lowercase();
+        match input.as_str() {
+            "odd" => return Some(Nth { a: 2, b: 1 }),
+            "even" => return Some(Nth { a: 2, b: 0 }),
+            _ => {}
+        }
+
+        match input.split_once('n') {
+            Some((a, b)) => {
+                let a = match a {
+                    "" | "+" => 1,
+                    "-" => -1,
+                    a => a.parse().ok()?,
+                };
+                let b = if b.is_empty() {
+                    0
+                } else {
+                    b.strip_prefix('+').unwrap_or(b).parse().ok()?
+                };
+                Some(Nth { a, b })
+            }
+            None => Some(Nth {
+                a: 0,
+                b: input.strip_prefix('+').unwrap_or(&input).parse().ok()?,
+            }),
+        }
+    }
+
+    // true ถ้ามี n >= 0 ที่ a*n + b == index
+    pub fn matches(&self, index: i32) -> bool {
+        if self.a == 0 {
+            return index == self.b;
+        }
+        let diff = index - self.b;
+        diff % self.a == 0 && diff / self.a >= 0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combinator {
+    Descendant,        // `a b`
+    Child,             // `a > b`
+    NextSibling,       // `a + b`
+    SubsequentSibling, // `a ~ b`
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompoundSelector {
+    // None = universal selector (`*`) or no type selector
+    pub tag: Option<String>,
+    pub ids: Vec<String>,
+    pub classes: Vec<String>,
+    pub states: Vec<PseudoState>,
+    pub pseudo_classes: Vec<PseudoClass>,
+    pub attributes: Vec<AttributeSelector>,
+    // pseudo-classes/elements that we can't evaluate
+    pub unsupported: bool,
+}
+
+pub fn parse_selector_list(input: &str) -> Vec<Selector> {
+    split_top_level(input, ',')
+        .iter()
+        .filter_map(|part| Selector::parse(part))
+        .collect()
+}
+
+impl Selector {
+    pub fn parse(input: &str) -> Option<Self> {
+        let chars: Vec<char> = input.trim().chars().collect();
+        if chars.is_empty() {
+            return None;
+        }
+
+        let mut compounds = Vec::new();
+        let mut combinators = Vec::new();
+        let mut pos = 0;
+
+        loop {
+            let (compound, next) = CompoundSelector::parse(&chars, pos)?;
+            compounds.push(compound);
+            pos = next;
+
+            // อ่าน combinator ระหว่าง compound selectors
+            let mut saw_whitespace = false;
+            while pos < chars.len() && chars[pos].is_whitespace() {
+                saw_whitespace = true;
+                pos += 1;
+            }
+            if pos >= chars.len() {
+                break;
+            }
+
+            let combinator = match chars[pos] {
+                '>' => Combinator::Child,
+                '+' => Combinator::NextSibling,
+                '~' => Combinator::SubsequentSibling,
+                _ if saw_whitespace => Combinator::Descendant,
+                _ => return None,
+            };
+            if combinator != Combinator::Descendant {
+                pos += 1;
+                while pos < chars.len() && chars[pos].is_whitespace() {
+                    pos += 1;
+                }
+            }
+            combinators.push(combinator);
+        }
+
+        // state ถูกติดตามเฉพาะของ element ที่ใช้ state styles เอง ไม่ใช่ของ ancestors / siblings
+        if compounds[..compounds.len() - 1]
+            .iter()
+            .any(CompoundSelector::has_state)
+        {
+            warn!(
+                "{}: :hover, :active and :focus are only supported on the last compound selector",
+                input.trim()
+            );
+            return None;
+        }
+
+        Some(Selector {
+            compounds,
+            combinators,
+        })
+    }
+
+    pub fn specificity(&self) -> Specificity {
+        self.compounds
+            .iter()
+            .map(CompoundSelector::specificity)
+            .fold(Specificity::default(), |total, s| total + s)
+    }
+
+    pub fn matches(&self, element: &ElementRef) -> bool {
+        self.matches_in_state(element, ElementState::default())
+    }
+
+    // state ใช้กับ subject (compound ขวาสุด) เท่านั้น
+    pub fn matches_in_state(&self, element: &ElementRef, state: ElementState) -> bool {
+        let last = self.compounds.len() - 1;
+        self.compounds[last].matches(element, state) && self.matches_at(last, element)
+    }
+
+    // true ถ้า selector ขึ้นกับ :hover, :active หรือ :focus
+    pub fn has_state(&self) -> bool {
+        self.compounds.iter().any(CompoundSelector::has_state)
+    }
+
+    // จับคู่จากขวาไปซ้าย โดย compound ที่ index ตรงกับ element แล้ว
+    fn matches_at(&self, index: usize, element: &ElementRef) -> bool {
+        if index == 0 {
+            return true;
+        }
+
+        let previous = &self.compounds[index - 1];
+        let matches_previous = |candidate: &ElementRef| {
+            previous.matches(candidate, ElementState::default())
+                && self.matches_at(index - 1, candidate)
+        };
+        match self.combinators[index - 1] {
+            Combinator::Descendant => element
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|ancestor| matches_previous(&ancestor)),
+            Combinator::Child => parent_element(element).is_some_and(|p| matches_previous(&p)),
+            Combinator::NextSibling => element
+                .prev_siblings()
+                .find_map(ElementRef::wrap)
+                .is_some_and(|sibling| matches_previous(&sibling)),
+            Combinator::SubsequentSibling => element
+                .prev_siblings()
+                .filter_map(ElementRef::wrap)
+                .any(|sibling| matches_previous(&sibling)),
+        }
+    }
+}
+
+impl CompoundSelector {
+    fn parse(chars: &[char], mut pos: usize) -> Option<(Self, usize)> {
+        let mut compound = CompoundSelector::default();
+        let start = pos;
+
+        if pos < chars.len() && chars[pos] == '*' {
+            pos += 1;
+        } else if pos < chars.len() && is_ident_char(chars[pos]) {
+            let (tag, next) = read_ident(chars, pos);
+            compound.tag = Some(tag.to_ascii_lowercase());
+            pos = next;
+        }
+
+        while pos < chars.len() {
+            match chars[pos] {
+                '.' => {
+                    let (class, next) = read_ident(chars, pos + 1);
+                    if class.is_empty() {
+                        return None;
+                    }
+                    compound.classes.push(class);
+                    pos = next;
+                }
+                '#' => {
+                    let (id, next) = read_ident(chars, pos + 1);
+                    if id.is_empty() {
+                        return None;
+                    }
+                    compound.ids.push(id);
+                    pos = next;
+                }
+                '[' => {
+                    let end = find_closing(chars, pos, '[', ']')?;
+                    let argument: String = chars[pos + 1..end].iter().collect();
+                    compound
+                        .attributes
+                        .push(AttributeSelector::parse(&argument)?);
+                    pos = end + 1;
+                }
+                ':' => {
+                    pos += 1;
+                    let is_element = pos < chars.len() && chars[pos] == ':';
+                    if is_element {
+                        pos += 1;
+                    }
+                    let (name, next) = read_ident(chars, pos);
+                    let name = name.to_ascii_lowercase();
+                    pos = next;
+                    if is_element {
+                        // pseudo-elements (::before, ::after) ไม่มี entity ให้ style
+                        compound.unsupported = true;
+                        if pos < chars.len() && chars[pos] == '(' {
+                            pos = find_closing(chars, pos, '(', ')')? + 1;
+                        }
+                        continue;
+                    }
+
+                    if pos < chars.len() && chars[pos] == '(' {
+                        let end = find_closing(chars, pos, '(', ')')?;
+                        let argument: String = chars[pos + 1..end].iter().collect();
+                        pos = end + 1;
+                        match parse_functional_pseudo_class(&name, &argument) {
+                            Some(pseudo_class) => compound.pseudo_classes.push(pseudo_class),
+                            None => compound.unsupported = true,
+                        }
+                        continue;
+                    }
+
+                    match name.as_str() {
+                        "hover" => compound.states.push(PseudoState::Hover),
+                        "active" => compound.states.push(PseudoState::Active),
+                        "focus" => compound.states.push(PseudoState::Focus),
+                        "focus-visible" => compound.states.push(PseudoState::FocusVisible),
+                        "root" => compound.pseudo_classes.push(PseudoClass::Root),
+                        "empty" => compound.pseudo_classes.push(PseudoClass::Empty),
+                        "first-child" => compound.pseudo_classes.push(PseudoClass::FirstChild),
+                        "last-child" => compound.pseudo_classes.push(PseudoClass::LastChild),
+                        "only-child" => compound.pseudo_classes.push(PseudoClass::OnlyChild),
+                        "first-of-type" => compound.pseudo_classes.push(PseudoClass::FirstOfType),
+                        "last-of-type" => compound.pseudo_classes.push(PseudoClass::LastOfType),
+                        "only-of-type" => compound.pseudo_classes.push(PseudoClass::OnlyOfType),
+                        _ => compound.unsupported = true,
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        if pos == start {
+            return None;
+        }
+        Some((compound, pos))
+    }
+
+    fn specificity(&self) -> Specificity {
+        let own = Specificity(
+            self.ids.len() as u32,
+            (self.classes.len() + self.attributes.len() + self.states.len()) as u32,
+            self.tag.is_some() as u32,
+        );
+        self.pseudo_classes
+            .iter()
+            .map(PseudoClass::specificity)
+            .fold(own, |total, s| total + s)
+    }
+
+    fn has_state(&self) -> bool {
+        !self.states.is_empty()
+            || self
+                .pseudo_classes
+                .iter()
+                .any(|pseudo_class| match pseudo_class {
+                    PseudoClass::Not(selectors)
+                    | PseudoClass::Is(selectors)
+                    | PseudoClass::Where(selectors) => selectors.iter().any(Selector::has_state),
+                    _ => false,
+                })
+    }
+
+    fn matches(&self, element: &ElementRef, state: ElementState) -> bool {
+        if self.unsupported || !self.states.iter().all(|s| state.contains(*s)) {
+            return false;
+        }
+        if !self
+            .pseudo_classes
+            .iter()
+            .all(|pseudo_class| pseudo_class.matches(element, state))
+        {
+            return false;
+        }
+
+        let value = element.value();
+        if let Some(tag) = &self.tag
+            && !value.name().eq_ignore_ascii_case(tag)
+        {
+            return false;
+        }
+        if self.ids.iter().any(|id| value.id() != Some(id.as_str())) {
+            return false;
+        }
+        if !self.attributes.iter().all(|attr| attr.matches(element)) {
+            return false;
+        }
+        self.classes
+            .iter()
+            .all(|class| value.classes().any(|c| c == class))
+    }
+}
+
+impl PseudoClass {
+    fn specificity(&self) -> Specificity {
+        match self {
+            // :not() / :is() ใช้ specificity ของ argument ที่ specific ที่สุด
+            PseudoClass::Not(selectors) | PseudoClass::Is(selectors) => selectors
+                .iter()
+                .map(Selector::specificity)
+                .max()
+                .unwrap_or_default(),
+            PseudoClass::Where(_) => Specificity::default(),
+            _ => Specificity(0, 1, 0),
+        }
+    }
+
+    fn matches(&self, element: &ElementRef, state: ElementState) -> bool {
+        match self {
+            PseudoClass::Root => parent_element(element).is_none(),
+            PseudoClass::Empty => element.children().all(|child| match child.value() {
+                scraper::Node::Element(_) => false,
+                scraper::Node::Text(text) => text.trim().is_empty(),
+                _ => true,
+            }),
+            PseudoClass::FirstChild => sibling_position(element, false, false) == 1,
+            PseudoClass::LastChild => sibling_position(element, true, false) == 1,
+            PseudoClass::OnlyChild => {
+                sibling_position(element, false, false) == 1
+                    && sibling_position(element, true, false) == 1
+            }
+            PseudoClass::FirstOfType => sibling_position(element, false, true) == 1,
+            PseudoClass::LastOfType => sibling_position(element, true, true) == 1,
+            PseudoClass::OnlyOfType => {
+                sibling_position(element, false, true) == 1
+                    && sibling_position(element, true, true) == 1
+            }
+            PseudoClass::NthChild(nth) => nth.matches(sibling_position(element, false, false)),
+            PseudoClass::NthLastChild(nth) => nth.matches(sibling_position(element, true, false)),
+            PseudoClass::NthOfType(nth) => nth.matches(sibling_position(element, false, true)),
+            PseudoClass::NthLastOfType(nth) => nth.matches(sibling_position(element, true, true)),
+            PseudoClass::Not(selectors) => !selectors
+                .iter()
+                .any(|selector| selector.matches_in_state(element, state)),
+            PseudoClass::Is(selectors) | PseudoClass::Where(selectors) => selectors
+                .iter()
+                .any(|selector| selector.matches_in_state(element, state)),
+        }
+    }
+}
+
+fn parse_functional_pseudo_class(name: &str, argument: &str) -> Option<PseudoClass> {
+    let pseudo_class = match name {
+        "nth-child" => PseudoClass::NthChild(Nth::parse(argument)?),
+        "nth-last-child" => PseudoClass::NthLastChild(Nth::parse(argument)?),
+        "nth-of-type" => PseudoClass::NthOfType(Nth::parse(argument)?),
+        "nth-last-of-type" => PseudoClass::NthLastOfType(Nth::parse(argument)?),
+        "not" | "is" | "where" | "matches" => {
+            let selectors: Vec<String> = split_top_level(argument, ',');
+            let parsed = parse_selector_list(argument);
+            if parsed.len() != selectors.len() {
+                return None;
+            }
+            match name {
+                "not" => PseudoClass::Not(parsed),
+                "where" => PseudoClass::Where(parsed),
+                _ => PseudoClass::Is(parsed),
+            }
+        }
+        _ => return None,
+    };
+    Some(pseudo_class)
+}
+
+// ตำแหน่งของ element ในบรรดา siblings (เริ่มที่ 1) นับจากหน้าหรือท้าย
+// of_type = นับเฉพาะ siblings ที่มี tag เดียวกัน
+fn sibling_position(element: &ElementRef, from_end: bool, of_type: bool) -> i32 {
+    let name = element.value().name();
+    let counts = |sibling: &ElementRef| !of_type || sibling.value().name() == name;
+    let preceding = if from_end {
+        element
+            .next_siblings()
+            .filter_map(ElementRef::wrap)
+            .filter(counts)
+            .count()
+    } else {
+        element
+            .prev_siblings()
+            .filter_map(ElementRef::wrap)
+            .filter(counts)
+            .count()
+    };
+    preceding as i32 + 1
+}
+
+fn parent_element<'a>(element: &ElementRef<'a>) -> Option<ElementRef<'a>> {
+    element.parent().and_then(ElementRef::wrap)
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_' || !c.is_ascii()
+}
+
+fn skip_whitespace(chars: &[char], pos: &mut usize) {
+    while *pos < chars.len() && chars[*pos].is_whitespace() {
+        *pos += 1;
+    }
+}
+
+fn read_ident(chars: &[char], mut pos: usize) -> (String, usize) {
+    let mut ident = String::new();
+    while pos < chars.len() {
+        let c = chars[pos];
+        if c == '\\' && pos + 1 < chars.len() {
+            ident.push(chars[pos + 1]);
+            pos += 2;
+        } else if is_ident_char(c) {
+            ident.push(c);
+            pos += 1;
+        } else {
+            break;
+        }
+    }
+    (ident, pos)
+}
+
+// หา index ของวงเล็บปิดที่คู่กับวงเล็บเปิดที่ตำแหน่ง start (ข้าม string ใน quotes)
+fn find_closing(chars: &[char], start: usize, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut pos = start;
+    while pos < chars.len() {
+        let c = chars[pos];
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) if c == '\\' => pos += 1,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == open => depth += 1,
+            None if c == close => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(pos);
+                }
+            }
+            None => {}
+        }
+        pos += 1;
+    }
+    None
+}
+
+fn split_top_level(input: &str, separator: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    for c in input.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '(' || c == '[' => depth += 1,
+            None if c == ')' || c == ']' => depth -= 1,
+            None if c == separator && depth == 0 => {
+                parts.push(std::mem::take(&mut current));
+                continue;
+            }
+            None => {}
+        }
+        current.push(c);
+    }
+    parts.push(current);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scraper::Html;
+
+    fn matches(html: &str, selector: &str, target: &str) -> bool {
+        let document = Html::parse_document(html);
+        let element = document
+            .select(&scraper::Selector::parse(target).unwrap())
+            .next()
+            .unwrap();
+        parse_selector_list(selector)
+            .iter()
+            .any(|selector| selector.matches(&element))
+    }
+
+    #[test]
+    fn combinators() {
+        let html = r#"<div id="root"><section class="list"><h2>t</h2><p id="a">1</p><span></span><p id="b">2</p></section><p id="c">3</p></div>"#;
+        let matches = |selector: &str, target: &str| matches(html, selector, target);
+
+        // descendant ข้ามหลายชั้นได้, child ต้องเป็น parent โดยตรง
+        assert!(matches("#root p", "#a"));
+        assert!(matches("div p", "#c"));
+        assert!(!matches("section p", "#c"));
+        assert!(matches("#root > p", "#c"));
+        assert!(!matches("#root > p", "#a"));
+        assert!(matches("div > .list > p", "#b"));
+
+        // + คือ sibling ก่อนหน้าตัวเดียว, ~ คือ sibling ก่อนหน้าตัวใดก็ได้
+        assert!(matches("h2 + p", "#a"));
+        assert!(!matches("h2 + p", "#b"));
+        assert!(matches("span + p", "#b"));
+        assert!(matches("h2 ~ p", "#b"));
+        assert!(!matches("span ~ p", "#a"));
+        assert!(matches(".list + p", "#c"));
+
+        // ผสมหลาย combinator
+        assert!(matches("div .list > h2 ~ span + p", "#b"));
+        assert!(!matches("div .list > h2 + span", "span"));
+    }
+
+    #[test]
+    fn specificity_ordering() {
+        let specificity = |selector: &str| Selector::parse(selector).unwrap().specificity();
+        assert_eq!(specificity("*"), Specificity(0, 0, 0));
+        assert_eq!(specificity("div p"), Specificity(0, 0, 2));
+        assert_eq!(
+            specificity("ul > li + li.item[data-x]"),
+            Specificity(0, 2, 3)
+        );
+        assert_eq!(specificity("#main .card ~ p:hover"), Specificity(1, 2, 1));
+
+        // id > class > type ไม่ว่าจำนวนของระดับที่ต่ำกว่าจะมากแค่ไหน
+        assert!(specificity("#a") > specificity(".a.b.c.d div span p"));
+        assert!(specificity(".a") > specificity("html body div section p"));
+        assert!(specificity("div.a") > specificity(".a"));
+    }
+
+    #[test]
+    fn structural_pseudo_classes() {
+        let html =
+            r#"<ul><li id="a">1</li><li id="b">2</li><li id="c"></li><span id="d">4</span></ul>"#;
+        let matches = |selector: &str, target: &str| matches(html, selector, target);
+
+        assert!(matches("li:first-child", "#a"));
+        assert!(matches("li:nth-child(odd)", "#a"));
+        assert!(!matches("li:nth-child(odd)", "#b"));
+        assert!(matches("li:nth-child(odd)", "#c"));
+        assert!(!matches("li:last-of-type", "#b"));
+        assert!(matches("li:last-of-type", "#c"));
+        assert!(matches("li:empty:not(:first-child)", "#c"));
+        assert!(!matches("li:empty", "#a"));
+        assert!(matches(":nth-last-child(-n+1)", "#d"));
+        assert!(!matches(":nth-last-child(-n+1)", "#c"));
+        assert!(matches("span:only-of-type", "#d"));
+        assert!(!matches("li:only-of-type", "#a"));
+    }
+
+    #[test]
+    fn attribute_selectors() {
+        let html = r#"<div><button id="a" data-state="open" lang="en-US" data-tags="menu primary" disabled>a</button><button id="b" data-state="Closed">b</button></div>"#;
+        let matches = |selector: &str, target: &str| matches(html, selector, target);
+
+        assert!(matches("[disabled]", "#a"));
+        assert!(!matches("[disabled]", "#b"));
+        assert!(matches("[data-state=\"open\"]", "#a"));
+        assert!(matches("[lang|=en]", "#a"));
+        assert!(matches("[data-tags~=primary]", "#a"));
+        assert!(!matches("[data-tags~=prim]", "#a"));
+        // i = ไม่สนตัวพิมพ์เล็กใหญ่ของค่า
+        assert!(matches("[data-state^=clo i]", "#b"));
+        assert!(!matches("[data-state^=clo]", "#b"));
+        assert!(matches("[data-state$=\"sed\"]", "#b"));
+        assert!(!matches("[data-state*=LOSE]", "#b"));
+    }
+}
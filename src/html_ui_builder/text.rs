@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use bevy::text::TextLayoutInfo;
+use bevy::ui::widget::TextUiWriter;
+
+const ELLIPSIS: char = '…';
+
+// TextSpan หรือ element ที่ไม่ได้กำหนด color เอง (ใช้สีของ ancestor ซึ่งเปลี่ยนตาม :hover ได้)
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct InheritedTextColor;
+
+// text-overflow: ellipsis — ตัดท้ายข้อความที่ยาวเกิน content box ของ parent แล้วต่อด้วย "…"
+#[derive(Component, Debug, Clone)]
+pub struct TextEllipsis {
+    // ข้อความเต็มของแต่ละ TextSpan ตามลำดับ
+    full: Vec<String>,
+    // จำนวนตัวอักษรรวมของทุก span ที่แสดงอยู่
+    visible: usize,
+    // ความกว้างที่ใช้ได้ล่าสุด (logical px เหมือน TextLayoutInfo)
+    available: f32,
+}
+
+impl TextEllipsis {
+    pub fn new(full: Vec<String>) -> Self {
+        Self {
+            visible: Self::count(&full),
+            full,
+            available: 0.0,
+        }
+    }
+
+    fn count(runs: &[String]) -> usize {
+        runs.iter().map(|run| run.chars().count()).sum()
+    }
+
+    // ข้อความที่ควรแสดงของแต่ละ span ("…" ต่อท้าย span สุดท้ายที่ยังมีตัวอักษร)
+    fn shown(&self) -> Vec<String> {
+        if self.visible >= Self::count(&self.full) {
+            return self.full.clone();
+        }
+        let mut remaining = self.visible;
+        let mut shown: Vec<String> = self
+            .full
+            .iter()
+            .map(|run| {
+                let part: String = run.chars().take(remaining).collect();
+                remaining -= part.chars().count();
+                part
+            })
+            .collect();
+        let last = shown.iter().rposition(|run| !run.is_empty()).unwrap_or(0);
+        if let Some(run) = shown.get_mut(last) {
+            run.truncate(run.trim_end().len());
+            run.push(ELLIPSIS);
+        }
+        shown
+    }
+}
+
+// วัดขนาดได้หลัง layout เท่านั้น จึงลดตัวอักษรทีละเฟรมจนพอดี (กว้างขึ้นก็เริ่มจากข้อความเต็มใหม่)
+pub fn truncate_text_with_ellipsis(
+    mut texts: Query<(Entity, &mut TextEllipsis, &TextLayoutInfo, &ChildOf)>,
+    parents: Query<&ComputedNode>,
+    mut writer: TextUiWriter,
+) {
+    for (entity, mut ellipsis, layout, child_of) in &mut texts {
+        let Ok(parent) = parents.get(child_of.parent()) else {
+            continue;
+        };
+        let inset =
+            parent.border.left + parent.border.right + parent.padding.left + parent.padding.right;
+        let available = (parent.size.x - inset).max(0.0) * parent.inverse_scale_factor();
+        if available > ellipsis.available {
+            ellipsis.visible = TextEllipsis::count(&ellipsis.full);
+        }
+        ellipsis.available = available;
+
+        // span 0 คือ Text ว่างของ block, runs เริ่มที่ span 1
+        let current: Vec<String> = (1..=ellipsis.full.len())
+            .map(|index| {
+                writer
+                    .get_text(entity, index)
+                    .map(|text| text.clone())
+                    .unwrap_or_default()
+            })
+            .collect();
+        // ขนาดที่วัดได้เป็นของข้อความที่แสดงอยู่ตอนนี้
+        if current == ellipsis.shown() && layout.size.x > available + 0.5 && ellipsis.visible > 0 {
+            let estimate = ellipsis.visible as f32 * available / layout.size.x;
+            ellipsis.visible = (estimate as usize).min(ellipsis.visible - 1);
+        }
+        for (index, shown) in ellipsis.shown().into_iter().enumerate() {
+            if current[index] != shown
+                && let Some(mut text) = writer.get_text(entity, index + 1)
+            {
+                *text = shown;
+            }
+        }
+    }
+}
@@ -1,93 +1,778 @@
-use super::css::{CssPropertyValue, CssStyleSheet};
+use super::background::{BackgroundGradient, BackgroundLayer, ImageFit};
+use super::calc::{CalcField, CalcStyles};
+use super::css::{
+    BORDER_SIDES, CssBackgroundSize, CssImage, CssLength, CssPropertyValue, CssRule_,
+    CssStyleSheet, DEFAULT_FONT_SIZE, GradientShape, GridLineValue, INHERITED_PROPERTIES,
+    RadialExtent, is_inherited_property, parse_declaration, property_names, substitute_var,
+};
+use super::element::INLINE_TAGS;
+use super::font::FontQuery;
+use super::selector::{ElementState, Specificity};
 use bevy::prelude::*;
-use lightningcss::values::color::CssColor;
+use bevy::text::LineHeight;
+use lightningcss::properties::CSSWideKeyword;
+use lightningcss::values::color::{
+    CssColor, FloatColor, LABColor, PredefinedColor, SystemColor, XYZd65,
+};
 use std::collections::HashMap;
 
+// `root_font_size` มาจาก root_font_size() — คำนวณครั้งเดียวต่อการ build แล้วส่งต่อลงไป
 pub fn compute_element_styles(
-    tag: &str,
-    id: &Option<String>,
-    classes: &[String],
-    stylesheet: &Box<CssStyleSheet>,
+    element: &scraper::ElementRef,
+    stylesheet: &CssStyleSheet,
+    parent: Option<&HashMap<String, CssPropertyValue>>,
+    root_font_size: f32,
+) -> HashMap<String, CssPropertyValue> {
+    compute_element_styles_in_state(
+        element,
+        stylesheet,
+        parent,
+        ElementState::default(),
+        root_font_size,
+    )
+}
+
+// Same as compute_element_styles but evaluates :hover/:active/:focus against `state`
+pub fn compute_element_styles_in_state(
+    element: &scraper::ElementRef,
+    stylesheet: &CssStyleSheet,
+    parent: Option<&HashMap<String, CssPropertyValue>>,
+    state: ElementState,
+    root_font_size: f32,
+) -> HashMap<String, CssPropertyValue> {
+    let mut computed = cascade(element, stylesheet, state);
+    substitute_variables(&mut computed, parent);
+    apply_inheritance(&mut computed, parent);
+    resolve_current_color(&mut computed, parent);
+    resolve_relative_lengths(&mut computed, parent, root_font_size);
+
+    computed
+}
+
+// Declared values ของ element หลัง cascade (ยังไม่รวม inheritance)
+fn cascade(
+    element: &scraper::ElementRef,
+    stylesheet: &CssStyleSheet,
+    state: ElementState,
 ) -> HashMap<String, CssPropertyValue> {
     let mut computed = HashMap::new();
 
-    // Apply tag styles
-    for rule in &stylesheet.rules {
-        if rule.selector == tag {
-            for (prop, value) in &rule.properties {
-                computed.insert(prop.clone(), value.clone());
-            }
+    // หา rules ที่ selector ตรงกับ element (ใช้ selector ที่ specific ที่สุดใน list)
+    let mut matched: Vec<(Specificity, usize, &CssRule_)> = stylesheet
+        .rules
+        .iter()
+        .enumerate()
+        .filter_map(|(source_order, rule)| {
+            rule.selectors
+                .iter()
+                .filter(|selector| selector.matches_in_state(element, state))
+                .map(|selector| selector.specificity())
+                .max()
+                .map(|specificity| (specificity, source_order, rule))
+        })
+        .collect();
+
+    // Cascade: specificity ก่อน แล้วตามลำดับใน stylesheet (rule หลังชนะ)
+    matched.sort_by_key(|(specificity, source_order, _)| (*specificity, *source_order));
+    for (_, _, rule) in &matched {
+        for (prop, value) in &rule.properties {
+            computed.insert(prop.clone(), value.clone());
+        }
+    }
+
+    // ตัวแปรที่ override จาก Rust มีผลเหมือน inline style บน root element
+    if element
+        .parent()
+        .and_then(scraper::ElementRef::wrap)
+        .is_none()
+    {
+        for (name, value) in &stylesheet.variables {
+            computed.insert(name.clone(), CssPropertyValue::Custom(value.clone()));
+        }
+    }
+
+    // !important declarations override every normal declaration
+    for (_, _, rule) in &matched {
+        for (prop, value) in &rule.important_properties {
+            computed.insert(prop.clone(), value.clone());
         }
     }
 
-    // Apply class styles
-    for class in classes {
-        let class_selector = format!(".{}", class);
-        for rule in &stylesheet.rules {
-            if rule.selector == class_selector {
-                for (prop, value) in &rule.properties {
-                    computed.insert(prop.clone(), value.clone());
+    computed
+}
+
+// true ถ้ามี rule ที่ใช้ :hover/:active/:focus ตรงกับ element นี้
+pub fn has_state_styles(element: &scraper::ElementRef, stylesheet: &CssStyleSheet) -> bool {
+    stylesheet.rules.iter().any(|rule| {
+        rule.selectors.iter().any(|selector| {
+            selector.has_state() && selector.matches_in_state(element, ElementState::ALL)
+        })
+    })
+}
+
+// Computes styles for an element, resolving inheritance through all of its ancestors
+pub fn compute_element_styles_with_ancestors(
+    element: &scraper::ElementRef,
+    stylesheet: &CssStyleSheet,
+    root_font_size: f32,
+) -> HashMap<String, CssPropertyValue> {
+    let parent = element
+        .parent()
+        .and_then(scraper::ElementRef::wrap)
+        .map(|parent| compute_element_styles_with_ancestors(&parent, stylesheet, root_font_size));
+    compute_element_styles(element, stylesheet, parent.as_ref(), root_font_size)
+}
+
+// Custom properties สืบทอดเสมอ แล้วแทน var() ใน declarations ของ element นี้
+fn substitute_variables(
+    computed: &mut HashMap<String, CssPropertyValue>,
+    parent: Option<&HashMap<String, CssPropertyValue>>,
+) {
+    for (name, value) in parent.into_iter().flatten() {
+        if name.starts_with("--") && !computed.contains_key(name) {
+            computed.insert(name.clone(), value.clone());
+        }
+    }
+
+    let raw: HashMap<String, String> = computed
+        .iter()
+        .filter_map(|(name, value)| match value {
+            CssPropertyValue::Custom(value) => Some((name.clone(), value.clone())),
+            _ => None,
+        })
+        .collect();
+    // custom property อ้างถึงกันเองได้ — จำกัดความลึกเพื่อตัดวงจร (--a: var(--b); --b: var(--a))
+    fn lookup(raw: &HashMap<String, String>, name: &str, depth: usize) -> Option<String> {
+        if depth > raw.len() {
+            return None;
+        }
+        let value = raw.get(name)?;
+        substitute_var(value, &|name| lookup(raw, name, depth + 1))
+    }
+
+    // ตัวแปรที่ไม่มีค่าและไม่มี fallback = invalid at computed-value time (เหมือน unset)
+    let names: Vec<String> = computed.keys().cloned().collect();
+    for name in names {
+        match computed[&name].clone() {
+            CssPropertyValue::Custom(_) => match lookup(&raw, &name, 0) {
+                Some(value) => {
+                    computed.insert(name, CssPropertyValue::Custom(value));
+                }
+                None => {
+                    computed.remove(&name);
+                }
+            },
+            // แต่ละ longhand ใช้เฉพาะค่าของตัวเองจาก shorthand ที่แทนค่าแล้ว
+            // (longhand ที่ชนะ cascade ภายหลังจึงไม่ถูกทับ)
+            CssPropertyValue::Var { property, value } => {
+                computed.remove(&name);
+                let Some(value) = substitute_var(&value, &|name| lookup(&raw, name, 0)) else {
+                    continue;
+                };
+                let mut parsed = parse_declaration(&property, &value);
+                if name == property {
+                    // ค่าที่ไม่ใช่ longhand (เช่น overflow-clip-margin-box) ไปกับตัว property เอง
+                    let longhands = property_names(&property);
+                    computed.extend(
+                        parsed
+                            .into_iter()
+                            .filter(|(key, _)| *key == name || !longhands.contains(key)),
+                    );
+                } else if let Some(value) = parsed.remove(&name) {
+                    computed.insert(name, value);
                 }
             }
+            _ => {}
         }
     }
+}
+
+fn apply_inheritance(
+    computed: &mut HashMap<String, CssPropertyValue>,
+    parent: Option<&HashMap<String, CssPropertyValue>>,
+) {
+    let parent_value = |name: &str| parent.and_then(|parent| parent.get(name)).cloned();
+
+    // Inherited properties ที่ไม่ได้กำหนดเองจะได้ค่าจาก parent
+    for name in INHERITED_PROPERTIES {
+        if !computed.contains_key(*name)
+            && let Some(value) = parent_value(name)
+        {
+            computed.insert(name.to_string(), value);
+        }
+    }
+
+    // Resolve inherit / initial / unset
+    let keywords: Vec<(String, CSSWideKeyword)> = computed
+        .iter()
+        .filter_map(|(name, value)| match value {
+            CssPropertyValue::Keyword(keyword) => Some((name.clone(), *keyword)),
+            _ => None,
+        })
+        .collect();
+    for (name, keyword) in keywords {
+        let inherit = match keyword {
+            CSSWideKeyword::Inherit => true,
+            CSSWideKeyword::Initial => false,
+            CSSWideKeyword::Unset | CSSWideKeyword::Revert | CSSWideKeyword::RevertLayer => {
+                is_inherited_property(&name)
+            }
+        };
+        // ไม่มีค่าใน map = ใช้ค่าเริ่มต้น (initial value)
+        match parent_value(&name).filter(|_| inherit) {
+            Some(value) => computed.insert(name, value),
+            None => computed.remove(&name),
+        };
+    }
+
+    // bolder / lighter คำนวณจาก font-weight ของ parent
+    if let Some(CssPropertyValue::String(relative)) = computed.get("font-weight") {
+        let parent_weight = match parent_value("font-weight") {
+            Some(CssPropertyValue::Number(weight)) => weight,
+            _ => 400.0,
+        };
+        let weight = match relative.as_str() {
+            "bolder" if parent_weight < 350.0 => 400.0,
+            "bolder" if parent_weight < 550.0 => 700.0,
+            "bolder" => parent_weight.max(900.0),
+            _ if parent_weight < 550.0 => 100.0,
+            _ if parent_weight < 750.0 => 400.0,
+            _ => 700.0,
+        };
+        computed.insert("font-weight".to_string(), CssPropertyValue::Number(weight));
+    }
+}
 
-    // Apply ID styles (highest specificity)
-    if let Some(id) = id {
-        let id_selector = format!("#{}", id);
-        for rule in &stylesheet.rules {
-            if rule.selector == id_selector {
-                for (prop, value) in &rule.properties {
-                    computed.insert(prop.clone(), value.clone());
+// currentColor = ค่า color ของ element เอง (สำหรับ property color คือค่าของ parent)
+fn resolve_current_color(
+    computed: &mut HashMap<String, CssPropertyValue>,
+    parent: Option<&HashMap<String, CssPropertyValue>>,
+) {
+    if let Some(CssPropertyValue::Color(CssColor::CurrentColor)) = computed.get("color") {
+        match parent.and_then(|parent| parent.get("color")).cloned() {
+            Some(color) => computed.insert("color".to_string(), color),
+            None => computed.remove("color"),
+        };
+    }
+    let Some(CssPropertyValue::Color(color)) = computed.get("color").cloned() else {
+        return;
+    };
+    for value in computed.values_mut() {
+        match value {
+            CssPropertyValue::Color(current @ CssColor::CurrentColor) => *current = color.clone(),
+            CssPropertyValue::Shadows(shadows) => {
+                for shadow in shadows {
+                    if shadow.color == CssColor::CurrentColor {
+                        shadow.color = color.clone();
+                    }
+                }
+            }
+            CssPropertyValue::Images(images) => {
+                for image in images {
+                    let CssImage::Gradient(gradient) = image else {
+                        continue;
+                    };
+                    for stop in &mut gradient.stops {
+                        if stop.color == CssColor::CurrentColor {
+                            stop.color = color.clone();
+                        }
+                    }
                 }
             }
+            _ => {}
         }
     }
+}
 
-    computed
+// font-size ของ root element ของ document ที่ element อยู่ (ค่าที่ rem อ้างถึง)
+pub fn root_font_size(element: &scraper::ElementRef, stylesheet: &CssStyleSheet) -> f32 {
+    let root = element
+        .ancestors()
+        .filter_map(scraper::ElementRef::wrap)
+        .last()
+        .unwrap_or(*element);
+    let mut root_styles = cascade(&root, stylesheet, ElementState::default());
+    substitute_variables(&mut root_styles, None);
+    resolve_font_size(
+        root_styles.get("font-size"),
+        DEFAULT_FONT_SIZE,
+        DEFAULT_FONT_SIZE,
+    )
+}
+
+fn resolve_font_size(
+    value: Option<&CssPropertyValue>,
+    parent_font_size: f32,
+    root_font_size: f32,
+) -> f32 {
+    match value {
+        Some(CssPropertyValue::Size(size)) => *size,
+        // em และ % ของ font-size อ้างถึง font-size ของ parent
+        Some(CssPropertyValue::Length(length)) => {
+            match length.resolve(parent_font_size, root_font_size) {
+                Val::Px(px) => px,
+                _ => parent_font_size,
+            }
+        }
+        _ => parent_font_size,
+    }
+}
+
+// แปลง em / rem เป็น px: font-size เทียบกับ parent, property อื่นเทียบกับ font-size ของ element เอง
+fn resolve_relative_lengths(
+    computed: &mut HashMap<String, CssPropertyValue>,
+    parent: Option<&HashMap<String, CssPropertyValue>>,
+    root_font_size: f32,
+) {
+    let parent_font_size = parent.map_or(DEFAULT_FONT_SIZE, extract_font_size);
+    let font_size = resolve_font_size(computed.get("font-size"), parent_font_size, root_font_size);
+    if computed.contains_key("font-size") {
+        computed.insert("font-size".to_string(), CssPropertyValue::Size(font_size));
+    }
+
+    let resolve = |length: &mut CssLength| {
+        *length = length.resolve_font_relative(font_size, root_font_size);
+    };
+    for value in computed.values_mut() {
+        match value {
+            CssPropertyValue::Length(length) => resolve(length),
+            CssPropertyValue::Rect {
+                top,
+                right,
+                bottom,
+                left,
+            } => [top, right, bottom, left].into_iter().for_each(resolve),
+            CssPropertyValue::Corner {
+                top_left,
+                top_right,
+                bottom_right,
+                bottom_left,
+            } => [top_left, top_right, bottom_right, bottom_left]
+                .into_iter()
+                .for_each(resolve),
+            CssPropertyValue::Shadows(shadows) => {
+                for shadow in shadows {
+                    [
+                        &mut shadow.x_offset,
+                        &mut shadow.y_offset,
+                        &mut shadow.blur,
+                        &mut shadow.spread,
+                    ]
+                    .into_iter()
+                    .for_each(resolve);
+                }
+            }
+            CssPropertyValue::Images(images) => {
+                for image in images {
+                    let CssImage::Gradient(gradient) = image else {
+                        continue;
+                    };
+                    if let GradientShape::Radial {
+                        extent: RadialExtent::Radius(x, y),
+                        ..
+                    } = &mut gradient.shape
+                    {
+                        resolve(x);
+                        resolve(y);
+                    }
+                    gradient
+                        .stops
+                        .iter_mut()
+                        .filter_map(|stop| stop.position.as_mut())
+                        .for_each(resolve);
+                }
+            }
+            CssPropertyValue::BackgroundSizes(sizes) => {
+                for size in sizes {
+                    if let CssBackgroundSize::Explicit(width, height) = size {
+                        resolve(width);
+                        resolve(height);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
 }
 
 pub fn convert_css_to_bevy_style(properties: &HashMap<String, CssPropertyValue>) -> Node {
     let mut node = Node::default();
+    // ปกติ em / rem ถูก resolve เป็น px ไปแล้วตอน compute styles
+    let font_size = extract_font_size(properties);
+    let val = |length: &CssLength| length.resolve(font_size, DEFAULT_FONT_SIZE);
 
-    if let Some(CssPropertyValue::Size(value)) = properties.get("width") {
-        node.width = Val::Px(*value);
+    for (name, size) in [
+        ("width", &mut node.width),
+        ("height", &mut node.height),
+        ("min-width", &mut node.min_width),
+        ("min-height", &mut node.min_height),
+        ("max-width", &mut node.max_width),
+        ("max-height", &mut node.max_height),
+    ] {
+        if let Some(CssPropertyValue::Length(value)) = properties.get(name) {
+            *size = val(value);
+        }
     }
-
-    if let Some(CssPropertyValue::Size(value)) = properties.get("height") {
-        node.height = Val::Px(*value);
+    if let Some(CssPropertyValue::Number(ratio)) = properties.get("aspect-ratio") {
+        node.aspect_ratio = Some(*ratio);
     }
 
-    // Handle padding
-    if let Some(CssPropertyValue::Rect {
-        top,
-        right,
-        bottom,
-        left,
-    }) = properties.get("padding")
-    {
-        node.padding = UiRect::new(*left, *right, *top, *bottom);
+    // Handle padding / margin (longhands ต่อด้าน ด้านที่ไม่ได้กำหนดเป็น 0)
+    let side = |name: &str| match properties.get(name) {
+        Some(CssPropertyValue::Length(length)) => val(length),
+        _ => Val::Px(0.0),
+    };
+    node.padding = UiRect::new(
+        side("padding-left"),
+        side("padding-right"),
+        side("padding-top"),
+        side("padding-bottom"),
+    );
+    node.margin = UiRect::new(
+        side("margin-left"),
+        side("margin-right"),
+        side("margin-top"),
+        side("margin-bottom"),
+    );
+
+    // Border — style เริ่มต้นคือ none ซึ่งทำให้ความกว้างเป็น 0
+    let border_width = |side: &str| {
+        let style = keyword(properties, &format!("border-{side}-style")).unwrap_or("none");
+        if matches!(style, "none" | "hidden") {
+            return Val::Px(0.0);
+        }
+        match properties.get(&format!("border-{side}-width")) {
+            Some(CssPropertyValue::Length(width)) => val(width),
+            // ค่าเริ่มต้นของ border-width คือ medium
+            _ => Val::Px(3.0),
+        }
+    };
+    node.border = UiRect::new(
+        border_width("left"),
+        border_width("right"),
+        border_width("top"),
+        border_width("bottom"),
+    );
+
+    // Positioning — static ไม่สนใจ top/right/bottom/left
+    let position = keyword(properties, "position").unwrap_or("static");
+    node.position_type = match position {
+        "absolute" | "fixed" => PositionType::Absolute,
+        _ => PositionType::Relative,
+    };
+    if position != "static" {
+        for (name, inset) in [
+            ("top", &mut node.top),
+            ("right", &mut node.right),
+            ("bottom", &mut node.bottom),
+            ("left", &mut node.left),
+        ] {
+            if let Some(CssPropertyValue::Length(value)) = properties.get(name) {
+                *inset = val(value);
+            }
+        }
     }
 
-    // Handle margin
-    if let Some(CssPropertyValue::Rect {
-        top,
-        right,
-        bottom,
-        left,
-    }) = properties.get("margin")
-    {
-        node.margin = UiRect::new(*left, *right, *top, *bottom);
+    // visible / clip คู่กับอีกแกนที่ scroll ได้จะกลายเป็น auto / hidden เหมือน browser
+    let overflow_x = keyword(properties, "overflow-x").unwrap_or("visible");
+    let overflow_y = keyword(properties, "overflow-y").unwrap_or("visible");
+    let scrollable = |value: &str| !matches!(value, "visible" | "clip");
+    let overflow_axis = |value: &str, other: &str| match (value, scrollable(other)) {
+        ("visible", false) => OverflowAxis::Visible,
+        ("clip", false) => OverflowAxis::Clip,
+        ("clip", true) | ("hidden", _) => OverflowAxis::Hidden,
+        // scroll / auto
+        _ => OverflowAxis::Scroll,
+    };
+    node.overflow = Overflow {
+        x: overflow_axis(overflow_x, overflow_y),
+        y: overflow_axis(overflow_y, overflow_x),
+    };
+    // browser ตัดที่ padding box เมื่อไม่ได้ระบุ visual box
+    node.overflow_clip_margin = OverflowClipMargin {
+        visual_box: match keyword(properties, "overflow-clip-margin-box") {
+            Some("content-box") => OverflowClipBox::ContentBox,
+            Some("border-box") => OverflowClipBox::BorderBox,
+            _ => OverflowClipBox::PaddingBox,
+        },
+        margin: match properties.get("overflow-clip-margin") {
+            Some(CssPropertyValue::Length(margin)) => match val(margin) {
+                Val::Px(margin) => margin.max(0.0),
+                _ => 0.0,
+            },
+            _ => 0.0,
+        },
+    };
+
+    // Flexbox (ค่าที่ไม่ได้กำหนดใช้ default ของ browser: row, stretch)
+    if let Some(display) = keyword(properties, "display") {
+        node.display = match display {
+            "none" => Display::None,
+            "grid" | "inline-grid" => Display::Grid,
+            "flex" | "inline-flex" => Display::Flex,
+            _ => Display::Block,
+        };
+    }
+    if let Some(direction) = keyword(properties, "flex-direction") {
+        node.flex_direction = match direction {
+            "row-reverse" => FlexDirection::RowReverse,
+            "column" => FlexDirection::Column,
+            "column-reverse" => FlexDirection::ColumnReverse,
+            _ => FlexDirection::Row,
+        };
+    }
+    if let Some(wrap) = keyword(properties, "flex-wrap") {
+        node.flex_wrap = match wrap {
+            "wrap" => FlexWrap::Wrap,
+            "wrap-reverse" => FlexWrap::WrapReverse,
+            _ => FlexWrap::NoWrap,
+        };
+    }
+    if let Some(CssPropertyValue::Number(grow)) = properties.get("flex-grow") {
+        node.flex_grow = *grow;
+    }
+    if let Some(CssPropertyValue::Number(shrink)) = properties.get("flex-shrink") {
+        node.flex_shrink = *shrink;
+    }
+    if let Some(CssPropertyValue::Length(basis)) = properties.get("flex-basis") {
+        node.flex_basis = val(basis);
+    }
+    if let Some(justify) = keyword(properties, "justify-content") {
+        node.justify_content = match strip_overflow_position(justify) {
+            "start" | "left" => JustifyContent::Start,
+            "end" | "right" => JustifyContent::End,
+            "flex-start" => JustifyContent::FlexStart,
+            "flex-end" => JustifyContent::FlexEnd,
+            "center" => JustifyContent::Center,
+            "stretch" => JustifyContent::Stretch,
+            "space-between" => JustifyContent::SpaceBetween,
+            "space-around" => JustifyContent::SpaceAround,
+            "space-evenly" => JustifyContent::SpaceEvenly,
+            _ => JustifyContent::Default,
+        };
+    }
+    if let Some(align) = keyword(properties, "align-items") {
+        node.align_items = match strip_overflow_position(align) {
+            "start" | "self-start" => AlignItems::Start,
+            "end" | "self-end" => AlignItems::End,
+            "flex-start" => AlignItems::FlexStart,
+            "flex-end" => AlignItems::FlexEnd,
+            "center" => AlignItems::Center,
+            "baseline" | "first baseline" | "last baseline" => AlignItems::Baseline,
+            "stretch" => AlignItems::Stretch,
+            _ => AlignItems::Default,
+        };
+    }
+    if let Some(align) = keyword(properties, "align-self") {
+        node.align_self = match strip_overflow_position(align) {
+            "start" | "self-start" => AlignSelf::Start,
+            "end" | "self-end" => AlignSelf::End,
+            "flex-start" => AlignSelf::FlexStart,
+            "flex-end" => AlignSelf::FlexEnd,
+            "center" => AlignSelf::Center,
+            "baseline" | "first baseline" | "last baseline" => AlignSelf::Baseline,
+            "stretch" => AlignSelf::Stretch,
+            _ => AlignSelf::Auto,
+        };
+    }
+    if let Some(align) = keyword(properties, "align-content") {
+        node.align_content = match strip_overflow_position(align) {
+            "start" => AlignContent::Start,
+            "end" => AlignContent::End,
+            "flex-start" => AlignContent::FlexStart,
+            "flex-end" => AlignContent::FlexEnd,
+            "center" => AlignContent::Center,
+            "stretch" => AlignContent::Stretch,
+            "space-between" => AlignContent::SpaceBetween,
+            "space-around" => AlignContent::SpaceAround,
+            "space-evenly" => AlignContent::SpaceEvenly,
+            _ => AlignContent::Default,
+        };
+    }
+    if let Some(justify) = keyword(properties, "justify-items") {
+        node.justify_items = match strip_overflow_position(justify) {
+            "start" | "self-start" | "left" | "flex-start" => JustifyItems::Start,
+            "end" | "self-end" | "right" | "flex-end" => JustifyItems::End,
+            "center" => JustifyItems::Center,
+            "baseline" | "first baseline" | "last baseline" => JustifyItems::Baseline,
+            "stretch" => JustifyItems::Stretch,
+            _ => JustifyItems::Default,
+        };
+    }
+    if let Some(justify) = keyword(properties, "justify-self") {
+        node.justify_self = match strip_overflow_position(justify) {
+            "start" | "self-start" | "left" | "flex-start" => JustifySelf::Start,
+            "end" | "self-end" | "right" | "flex-end" => JustifySelf::End,
+            "center" => JustifySelf::Center,
+            "baseline" | "first baseline" | "last baseline" => JustifySelf::Baseline,
+            "stretch" => JustifySelf::Stretch,
+            _ => JustifySelf::Auto,
+        };
+    }
+    if let Some(CssPropertyValue::Length(gap)) = properties.get("row-gap") {
+        node.row_gap = val(gap);
+    }
+    if let Some(CssPropertyValue::Length(gap)) = properties.get("column-gap") {
+        node.column_gap = val(gap);
     }
 
-    // Default layout for containers
-    node.flex_direction = FlexDirection::Column;
-    node.justify_content = JustifyContent::Center;
-    node.align_items = AlignItems::Center;
+    // Grid
+    if let Some(CssPropertyValue::GridTemplate(tracks)) = properties.get("grid-template-rows") {
+        node.grid_template_rows = tracks.clone();
+    }
+    if let Some(CssPropertyValue::GridTemplate(tracks)) = properties.get("grid-template-columns") {
+        node.grid_template_columns = tracks.clone();
+    }
+    if let Some(CssPropertyValue::GridTracks(tracks)) = properties.get("grid-auto-rows") {
+        node.grid_auto_rows = tracks.clone();
+    }
+    if let Some(CssPropertyValue::GridTracks(tracks)) = properties.get("grid-auto-columns") {
+        node.grid_auto_columns = tracks.clone();
+    }
+    if let Some(flow) = keyword(properties, "grid-auto-flow") {
+        let dense = flow.contains("dense");
+        node.grid_auto_flow = match (flow.contains("column"), dense) {
+            (true, true) => GridAutoFlow::ColumnDense,
+            (true, false) => GridAutoFlow::Column,
+            (false, true) => GridAutoFlow::RowDense,
+            (false, false) => GridAutoFlow::Row,
+        };
+    }
+    node.grid_row = grid_placement(properties, "grid-row-start", "grid-row-end");
+    node.grid_column = grid_placement(properties, "grid-column-start", "grid-column-end");
 
     node
 }
 
+// calc() ที่ resolve เป็น Val ตอน build ไม่ได้ (convert_css_to_bevy_style ใส่ Val::Auto ไว้แทน)
+pub fn extract_calc_styles(properties: &HashMap<String, CssPropertyValue>) -> CalcStyles {
+    let mut calc_styles = Vec::new();
+    let mut push = |field: CalcField, length: &CssLength| {
+        if let CssLength::Calc(calc) = length {
+            calc_styles.push((field, calc.as_ref().clone()));
+        }
+    };
+
+    let mut lengths = vec![
+        ("width", CalcField::Width),
+        ("height", CalcField::Height),
+        ("min-width", CalcField::MinWidth),
+        ("min-height", CalcField::MinHeight),
+        ("max-width", CalcField::MaxWidth),
+        ("max-height", CalcField::MaxHeight),
+        ("flex-basis", CalcField::FlexBasis),
+        ("row-gap", CalcField::RowGap),
+        ("column-gap", CalcField::ColumnGap),
+        ("padding-top", CalcField::PaddingTop),
+        ("padding-right", CalcField::PaddingRight),
+        ("padding-bottom", CalcField::PaddingBottom),
+        ("padding-left", CalcField::PaddingLeft),
+        ("margin-top", CalcField::MarginTop),
+        ("margin-right", CalcField::MarginRight),
+        ("margin-bottom", CalcField::MarginBottom),
+        ("margin-left", CalcField::MarginLeft),
+    ];
+    if keyword(properties, "position").is_some_and(|position| position != "static") {
+        lengths.extend([
+            ("top", CalcField::Top),
+            ("right", CalcField::Right),
+            ("bottom", CalcField::Bottom),
+            ("left", CalcField::Left),
+        ]);
+    }
+    for (name, field) in lengths {
+        if let Some(CssPropertyValue::Length(length)) = properties.get(name) {
+            push(field, length);
+        }
+    }
+
+    CalcStyles(calc_styles)
+}
+
+fn grid_placement(
+    properties: &HashMap<String, CssPropertyValue>,
+    start: &str,
+    end: &str,
+) -> GridPlacement {
+    let line = |name: &str| match properties.get(name) {
+        Some(CssPropertyValue::GridLine(line)) => *line,
+        _ => GridLineValue::Auto,
+    };
+    match (line(start), line(end)) {
+        (GridLineValue::Line(start), GridLineValue::Line(end)) => {
+            GridPlacement::start_end(start, end)
+        }
+        (GridLineValue::Line(start), GridLineValue::Span(span)) => {
+            GridPlacement::start_span(start, span)
+        }
+        (GridLineValue::Line(start), GridLineValue::Auto) => GridPlacement::start(start),
+        (GridLineValue::Span(span), GridLineValue::Line(end)) => {
+            GridPlacement::end(end).set_span(span)
+        }
+        (GridLineValue::Auto, GridLineValue::Line(end)) => GridPlacement::end(end),
+        (GridLineValue::Span(span), _) | (GridLineValue::Auto, GridLineValue::Span(span)) => {
+            GridPlacement::span(span)
+        }
+        (GridLineValue::Auto, GridLineValue::Auto) => GridPlacement::auto(),
+    }
+}
+
+fn keyword<'a>(properties: &'a HashMap<String, CssPropertyValue>, name: &str) -> Option<&'a str> {
+    match properties.get(name) {
+        Some(CssPropertyValue::String(value)) => Some(value.as_str()),
+        _ => None,
+    }
+}
+
+// `safe center` / `unsafe end` → `center` / `end` (Bevy ไม่รองรับ overflow position)
+fn strip_overflow_position(value: &str) -> &str {
+    value
+        .strip_prefix("safe ")
+        .or_else(|| value.strip_prefix("unsafe "))
+        .unwrap_or(value)
+}
+
+// hidden / collapse ซ่อน element (collapse ไม่คืนพื้นที่เหมือน browser) — visibility สืบทอดผ่าน
+// Visibility::Inherited จึงใช้ Visible เฉพาะเมื่อ element กลับมาแสดงใต้ parent ที่ซ่อนอยู่
+pub fn extract_visibility(
+    properties: &HashMap<String, CssPropertyValue>,
+    parent: &HashMap<String, CssPropertyValue>,
+) -> Visibility {
+    let hidden = |properties| {
+        matches!(
+            keyword(properties, "visibility"),
+            Some("hidden" | "collapse")
+        )
+    };
+    match (hidden(properties), hidden(parent)) {
+        (true, false) => Visibility::Hidden,
+        (false, true) => Visibility::Visible,
+        _ => Visibility::Inherited,
+    }
+}
+
+// opacity ไม่สืบทอด แต่คูณกับ opacity ของ ancestors ตอน spawn
+pub fn extract_opacity(properties: &HashMap<String, CssPropertyValue>) -> f32 {
+    match properties.get("opacity") {
+        Some(CssPropertyValue::Number(opacity)) => opacity.clamp(0.0, 1.0),
+        _ => 1.0,
+    }
+}
+
+// z-index ของ `position: fixed` ไม่ขึ้นกับ stacking context ของ parent → GlobalZIndex
+pub fn extract_z_index(
+    properties: &HashMap<String, CssPropertyValue>,
+) -> (Option<ZIndex>, Option<GlobalZIndex>) {
+    let Some(CssPropertyValue::Integer(z_index)) = properties.get("z-index") else {
+        return (None, None);
+    };
+    if keyword(properties, "position") == Some("fixed") {
+        (None, Some(GlobalZIndex(*z_index)))
+    } else {
+        (Some(ZIndex(*z_index)), None)
+    }
+}
+
 pub fn extract_background_color(properties: &HashMap<String, CssPropertyValue>) -> BackgroundColor {
     if let Some(CssPropertyValue::Color(color)) = properties.get("background-color") {
         BackgroundColor(css_color_to_bevy_color(color))
@@ -96,6 +781,159 @@ pub fn extract_background_color(properties: &HashMap<String, CssPropertyValue>)
     }
 }
 
+// BorderColor ของ Bevy มีสีเดียวทุกด้าน → ใช้สีของด้านบน (ค่าเริ่มต้นคือ currentColor)
+pub fn extract_border_color(properties: &HashMap<String, CssPropertyValue>) -> BorderColor {
+    let color = BORDER_SIDES
+        .iter()
+        .find_map(
+            |side| match properties.get(&format!("border-{side}-color")) {
+                Some(CssPropertyValue::Color(color)) => Some(css_color_to_bevy_color(color)),
+                _ => None,
+            },
+        )
+        .unwrap_or_else(|| extract_text_color(properties));
+    BorderColor(color)
+}
+
+pub fn extract_box_shadow(properties: &HashMap<String, CssPropertyValue>) -> BoxShadow {
+    let Some(CssPropertyValue::Shadows(shadows)) = properties.get("box-shadow") else {
+        return BoxShadow::default();
+    };
+    let font_size = extract_font_size(properties);
+    let val = |length: &CssLength| length.resolve(font_size, DEFAULT_FONT_SIZE);
+    BoxShadow(
+        shadows
+            .iter()
+            .map(|shadow| ShadowStyle {
+                color: css_color_to_bevy_color(&shadow.color),
+                x_offset: val(&shadow.x_offset),
+                y_offset: val(&shadow.y_offset),
+                spread_radius: val(&shadow.spread),
+                blur_radius: val(&shadow.blur),
+            })
+            .collect(),
+    )
+}
+
+// layer แรกของ background-image อยู่บนสุด จึงคืนค่าตามลำดับการวาด (ล่างสุดก่อน)
+// แล้วตามด้วย border-image ซึ่งวาดทับ background
+pub fn extract_background_layers(
+    properties: &HashMap<String, CssPropertyValue>,
+) -> Vec<BackgroundLayer> {
+    let font_size = extract_font_size(properties);
+    let val = |length: &CssLength| length.resolve(font_size, DEFAULT_FONT_SIZE);
+    let mut layers = Vec::new();
+
+    if let Some(CssPropertyValue::Images(images)) = properties.get("background-image") {
+        let sizes = match properties.get("background-size") {
+            Some(CssPropertyValue::BackgroundSizes(sizes)) => sizes.as_slice(),
+            _ => &[],
+        };
+        let repeats = match properties.get("background-repeat") {
+            Some(CssPropertyValue::BackgroundRepeats(repeats)) => repeats.as_slice(),
+            _ => &[],
+        };
+        for (index, image) in images.iter().enumerate().rev() {
+            // ถ้ามีค่าน้อยกว่าจำนวน layer ให้วนใช้ซ้ำเหมือน browser
+            let size = sizes.get(index % sizes.len().max(1));
+            let (x, y) = repeats
+                .get(index % repeats.len().max(1))
+                .copied()
+                .unwrap_or((true, true));
+            let fit = match size {
+                Some(CssBackgroundSize::Cover) => ImageFit::Cover,
+                Some(CssBackgroundSize::Contain) => ImageFit::Contain,
+                Some(CssBackgroundSize::Explicit(width, height))
+                    if (val(width), val(height)) != (Val::Auto, Val::Auto) =>
+                {
+                    ImageFit::Size(val(width), val(height))
+                }
+                _ => ImageFit::Repeat { x, y },
+            };
+            match image {
+                CssImage::None => {}
+                CssImage::Url(path) => layers.push(BackgroundLayer::Image {
+                    path: path.clone(),
+                    fit,
+                }),
+                CssImage::Gradient(gradient) => {
+                    layers.push(BackgroundLayer::Gradient(BackgroundGradient {
+                        shape: gradient.shape.clone(),
+                        repeating: gradient.repeating,
+                        stops: gradient
+                            .stops
+                            .iter()
+                            .map(|stop| {
+                                (
+                                    css_color_to_bevy_color(&stop.color),
+                                    stop.position.as_ref().map(val),
+                                )
+                            })
+                            .collect(),
+                    }))
+                }
+            }
+        }
+    }
+
+    // border-image แบบ gradient ยังไม่รองรับ
+    if let Some(CssPropertyValue::Images(images)) = properties.get("border-image-source")
+        && let Some(CssImage::Url(path)) = images.first()
+    {
+        // ค่าเริ่มต้นของ border-image-slice คือ 100%
+        let slices = match properties.get("border-image-slice") {
+            Some(CssPropertyValue::Rect {
+                top,
+                right,
+                bottom,
+                left,
+            }) => [val(top), val(right), val(bottom), val(left)],
+            _ => [Val::Percent(100.0); 4],
+        };
+        // Bevy ใช้โหมดเดียวกันทุกด้าน → ใช้ค่าของแกนนอน
+        let tile = keyword(properties, "border-image-repeat")
+            .and_then(|repeat| repeat.split_whitespace().next())
+            .is_some_and(|repeat| repeat != "stretch");
+        layers.push(BackgroundLayer::Image {
+            path: path.clone(),
+            fit: ImageFit::Sliced { slices, tile },
+        });
+    }
+    layers
+}
+
+// object-fit ของ <img> (ค่าเริ่มต้นคือ fill)
+pub fn extract_object_fit(properties: &HashMap<String, CssPropertyValue>) -> ImageFit {
+    match keyword(properties, "object-fit") {
+        Some("cover") => ImageFit::Cover,
+        Some("contain" | "scale-down") => ImageFit::Contain,
+        Some("none") => ImageFit::Natural,
+        _ => ImageFit::Stretch,
+    }
+}
+
+// outline-style: none (ค่าเริ่มต้น) = ไม่มี outline, auto วาดเป็นเส้นทึบ
+pub fn extract_outline(properties: &HashMap<String, CssPropertyValue>) -> Option<Outline> {
+    let style = keyword(properties, "outline-style").unwrap_or("none");
+    if matches!(style, "none" | "hidden") {
+        return None;
+    }
+    let font_size = extract_font_size(properties);
+    let length = |name: &str, default: Val| match properties.get(name) {
+        Some(CssPropertyValue::Length(length)) => length.resolve(font_size, DEFAULT_FONT_SIZE),
+        _ => default,
+    };
+    let color = match properties.get("outline-color") {
+        Some(CssPropertyValue::Color(color)) => css_color_to_bevy_color(color),
+        _ => extract_text_color(properties),
+    };
+    Some(Outline::new(
+        length("outline-width", Val::Px(3.0)),
+        length("outline-offset", Val::ZERO),
+        color,
+    ))
+}
+
 pub fn extract_border_radius(properties: &HashMap<String, CssPropertyValue>) -> BorderRadius {
     if let Some(CssPropertyValue::Corner {
         top_left,
@@ -104,7 +942,14 @@ pub fn extract_border_radius(properties: &HashMap<String, CssPropertyValue>) ->
         bottom_left,
     }) = properties.get("border-radius")
     {
-        BorderRadius::new(*top_left, *top_right, *bottom_right, *bottom_left)
+        let font_size = extract_font_size(properties);
+        let val = |length: &CssLength| length.resolve(font_size, DEFAULT_FONT_SIZE);
+        BorderRadius::new(
+            val(top_left),
+            val(top_right),
+            val(bottom_right),
+            val(bottom_left),
+        )
     } else {
         BorderRadius::default()
     }
@@ -122,18 +967,1248 @@ pub fn extract_font_size(properties: &HashMap<String, CssPropertyValue>) -> f32
     let size = properties.get("font-size");
     match size {
         Some(CssPropertyValue::Size(size)) => *size,
-        _ => 16.0,
+        _ => DEFAULT_FONT_SIZE,
     }
 }
 
+// font-style: oblique ใช้ไฟล์ italic เหมือน browser เมื่อไม่มี oblique แยก
+pub fn extract_font(properties: &HashMap<String, CssPropertyValue>) -> FontQuery {
+    let families = keyword(properties, "font-family")
+        .map(|families| {
+            families
+                .split(',')
+                .map(str::trim)
+                .filter(|family| !family.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default();
+    let weight = match properties.get("font-weight") {
+        Some(CssPropertyValue::Number(weight)) => *weight,
+        _ => 400.0,
+    };
+    let italic = keyword(properties, "font-style")
+        .is_some_and(|style| style.starts_with("italic") || style.starts_with("oblique"));
+    FontQuery {
+        families,
+        weight,
+        italic,
+    }
+}
+
+// text-align → การจัดบรรทัด, white-space: nowrap / pre → ไม่ตัดบรรทัด
+pub fn extract_text_layout(properties: &HashMap<String, CssPropertyValue>) -> TextLayout {
+    let justify = match keyword(properties, "text-align") {
+        Some("center") => JustifyText::Center,
+        Some("right" | "end") => JustifyText::Right,
+        Some("justify") => JustifyText::Justified,
+        _ => JustifyText::Left,
+    };
+    let linebreak = match keyword(properties, "white-space") {
+        Some("nowrap" | "pre") => LineBreak::NoWrap,
+        _ => LineBreak::WordBoundary,
+    };
+    TextLayout::new(justify, linebreak)
+}
+
+// line-height: normal ใช้ค่าเริ่มต้นของ Bevy (1.2 เท่าของ font-size)
+pub fn extract_line_height(properties: &HashMap<String, CssPropertyValue>) -> LineHeight {
+    match properties.get("line-height") {
+        Some(CssPropertyValue::Number(multiplier)) => LineHeight::RelativeToFont(*multiplier),
+        Some(CssPropertyValue::Length(length)) => {
+            match length.resolve(extract_font_size(properties), DEFAULT_FONT_SIZE) {
+                Val::Px(height) => LineHeight::Px(height),
+                _ => LineHeight::default(),
+            }
+        }
+        _ => LineHeight::default(),
+    }
+}
+
+// text-overflow: ellipsis มีผลเมื่อ element ตัดเนื้อหาที่ล้นแนวนอน
+pub fn extract_text_ellipsis(properties: &HashMap<String, CssPropertyValue>) -> bool {
+    let overflow_x = keyword(properties, "overflow-x").unwrap_or("visible");
+    let overflow_y = keyword(properties, "overflow-y").unwrap_or("visible");
+    // overflow-x: visible คู่กับ overflow-y ที่ scroll ได้จะกลายเป็น auto (ตัดเนื้อหาเช่นกัน)
+    keyword(properties, "text-overflow") == Some("ellipsis")
+        && (overflow_x != "visible" || !matches!(overflow_y, "visible" | "clip"))
+}
+
+// white-space: normal / nowrap ยุบช่องว่างและขึ้นบรรทัดใหม่, pre-line เก็บการขึ้นบรรทัดใหม่,
+// pre / pre-wrap / break-spaces เก็บทุกอย่างตามเดิม
+pub fn apply_white_space(text: &str, properties: &HashMap<String, CssPropertyValue>) -> String {
+    if text.trim().is_empty() {
+        return String::new();
+    }
+    let text = collapse_white_space(text, properties);
+    if preserves_white_space(properties) {
+        text
+    } else {
+        text.trim().to_string()
+    }
+}
+
+pub fn preserves_white_space(properties: &HashMap<String, CssPropertyValue>) -> bool {
+    matches!(
+        keyword(properties, "white-space"),
+        Some("pre" | "pre-wrap" | "break-spaces")
+    )
+}
+
+// ยุบช่องว่างต่อเนื่องเหลือช่องเดียวแต่ไม่ตัดหัวท้าย (ข้อความอาจอยู่ติดกับ inline element อื่น)
+pub fn collapse_white_space(text: &str, properties: &HashMap<String, CssPropertyValue>) -> String {
+    let collapse = |line: &str| {
+        let mut collapsed = String::with_capacity(line.len());
+        for (index, word) in line.split_whitespace().enumerate() {
+            if index > 0 {
+                collapsed.push(' ');
+            }
+            collapsed.push_str(word);
+        }
+        if line.starts_with(char::is_whitespace) && !collapsed.is_empty() {
+            collapsed.insert(0, ' ');
+        }
+        if line.ends_with(char::is_whitespace) || (collapsed.is_empty() && !line.is_empty()) {
+            collapsed.push(' ');
+        }
+        collapsed
+    };
+    if preserves_white_space(properties) {
+        return text.to_string();
+    }
+    if keyword(properties, "white-space") != Some("pre-line") {
+        return collapse(text);
+    }
+    // pre-line: ช่องว่างรอบการขึ้นบรรทัดใหม่หายไป
+    let lines: Vec<&str> = text.split('\n').collect();
+    let last = lines.len() - 1;
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let line = collapse(line);
+            let line = if index > 0 { line.trim_start() } else { &line };
+            let line = if index < last { line.trim_end() } else { line };
+            line.to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn is_display_none(properties: &HashMap<String, CssPropertyValue>) -> bool {
+    keyword(properties, "display") == Some("none")
+}
+
+// element ที่เป็น inline (ไม่มีกล่องของตัวเอง) ตาม tag เว้นแต่ display เปลี่ยนเป็นอย่างอื่น
+pub fn is_inline_element(tag: &str, properties: &HashMap<String, CssPropertyValue>) -> bool {
+    INLINE_TAGS.contains(&tag) && matches!(keyword(properties, "display"), None | Some("inline"))
+}
+
+// currentColor ถูกแทนค่าตอน compute styles แล้ว ถ้ายังเหลือแสดงว่าไม่มี color → ค่าเริ่มต้น (ดำ)
 pub fn css_color_to_bevy_color(css_color: &CssColor) -> Color {
     match css_color {
-        CssColor::RGBA(rgba) => Color::srgba(
-            rgba.red as f32 / 255.0,
-            rgba.green as f32 / 255.0,
-            rgba.blue as f32 / 255.0,
-            rgba.alpha as f32 / 255.0,
-        ),
-        _ => Color::BLACK,
+        CssColor::CurrentColor => Color::BLACK,
+        CssColor::RGBA(rgba) => Color::srgba_u8(rgba.red, rgba.green, rgba.blue, rgba.alpha),
+        CssColor::Float(color) => match **color {
+            // rgb() แบบ float ใช้ช่วง 0-255 เหมือน RGBA
+            FloatColor::RGB(rgb) => Color::srgba(
+                channel(rgb.r) / 255.0,
+                channel(rgb.g) / 255.0,
+                channel(rgb.b) / 255.0,
+                channel(rgb.alpha),
+            ),
+            FloatColor::HSL(hsl) => Color::hsla(
+                channel(hsl.h),
+                channel(hsl.s),
+                channel(hsl.l),
+                channel(hsl.alpha),
+            ),
+            FloatColor::HWB(hwb) => Color::hwba(
+                channel(hwb.h),
+                channel(hwb.w),
+                channel(hwb.b),
+                channel(hwb.alpha),
+            ),
+        },
+        CssColor::LAB(color) => match **color {
+            // lab() / lch() ของ CSS ใช้ white point D50 แต่ Laba / Lcha ของ Bevy ใช้ D65
+            // จึงแปลงผ่าน XYZ D65 แทน
+            LABColor::LAB(lab) => xyz_d65_to_bevy(XYZd65::from(lab)),
+            LABColor::LCH(lch) => xyz_d65_to_bevy(XYZd65::from(lch)),
+            LABColor::OKLAB(oklab) => Color::oklaba(
+                channel(oklab.l),
+                channel(oklab.a),
+                channel(oklab.b),
+                channel(oklab.alpha),
+            ),
+            LABColor::OKLCH(oklch) => Color::oklcha(
+                channel(oklch.l),
+                channel(oklch.c),
+                channel(oklch.h),
+                channel(oklch.alpha),
+            ),
+        },
+        CssColor::Predefined(color) => match **color {
+            PredefinedColor::SRGB(srgb) => Color::srgba(
+                channel(srgb.r),
+                channel(srgb.g),
+                channel(srgb.b),
+                channel(srgb.alpha),
+            ),
+            PredefinedColor::SRGBLinear(linear) => Color::linear_rgba(
+                channel(linear.r),
+                channel(linear.g),
+                channel(linear.b),
+                channel(linear.alpha),
+            ),
+            // Bevy ไม่มี display-p3 / a98-rgb / prophoto-rgb / rec2020 → แปลงเป็น XYZ
+            PredefinedColor::DisplayP3(p3) => xyz_d65_to_bevy(XYZd65::from(p3)),
+            PredefinedColor::A98(a98) => xyz_d65_to_bevy(XYZd65::from(a98)),
+            PredefinedColor::ProPhoto(prophoto) => xyz_d65_to_bevy(XYZd65::from(prophoto)),
+            PredefinedColor::Rec2020(rec2020) => xyz_d65_to_bevy(XYZd65::from(rec2020)),
+            PredefinedColor::XYZd50(xyz) => xyz_d65_to_bevy(XYZd65::from(xyz)),
+            PredefinedColor::XYZd65(xyz) => xyz_d65_to_bevy(xyz),
+        },
+        // ไม่มี color scheme → ใช้สีของ light mode
+        CssColor::LightDark(light, _) => css_color_to_bevy_color(light),
+        CssColor::System(system) => system_color(*system),
+    }
+}
+
+// `none` components ถูกเก็บเป็น NaN
+fn channel(value: f32) -> f32 {
+    if value.is_nan() { 0.0 } else { value }
+}
+
+fn xyz_d65_to_bevy(xyz: XYZd65) -> Color {
+    Color::xyza(
+        channel(xyz.x),
+        channel(xyz.y),
+        channel(xyz.z),
+        channel(xyz.alpha),
+    )
+}
+
+// ค่าของ system colors ตาม light theme ของ browser ทั่วไป
+fn system_color(system: SystemColor) -> Color {
+    match system {
+        SystemColor::Canvas
+        | SystemColor::Field
+        | SystemColor::ActiveCaption
+        | SystemColor::AppWorkspace
+        | SystemColor::Background
+        | SystemColor::InactiveCaption
+        | SystemColor::InfoBackground
+        | SystemColor::Menu
+        | SystemColor::Scrollbar
+        | SystemColor::Window => Color::WHITE,
+        SystemColor::CanvasText
+        | SystemColor::FieldText
+        | SystemColor::ButtonText
+        | SystemColor::HighlightText
+        | SystemColor::MarkText
+        | SystemColor::CaptionText
+        | SystemColor::InfoText
+        | SystemColor::MenuText
+        | SystemColor::WindowText => Color::BLACK,
+        SystemColor::ButtonFace
+        | SystemColor::ButtonHighlight
+        | SystemColor::ButtonShadow
+        | SystemColor::ThreeDFace => Color::srgb_u8(0xef, 0xef, 0xef),
+        SystemColor::ButtonBorder
+        | SystemColor::ActiveBorder
+        | SystemColor::InactiveBorder
+        | SystemColor::ThreeDDarkShadow
+        | SystemColor::ThreeDHighlight
+        | SystemColor::ThreeDLightShadow
+        | SystemColor::ThreeDShadow
+        | SystemColor::WindowFrame => Color::srgb_u8(0x76, 0x76, 0x76),
+        SystemColor::GrayText | SystemColor::InactiveCaptionText => {
+            Color::srgb_u8(0x6d, 0x6d, 0x6d)
+        }
+        SystemColor::AccentColor | SystemColor::SelectedItem => Color::srgb_u8(0x00, 0x75, 0xff),
+        SystemColor::AccentColorText | SystemColor::SelectedItemText => Color::WHITE,
+        SystemColor::Highlight => Color::srgb_u8(0xb4, 0xd5, 0xfe),
+        SystemColor::LinkText => Color::srgb_u8(0x00, 0x00, 0xee),
+        SystemColor::VisitedText => Color::srgb_u8(0x55, 0x1a, 0x8b),
+        SystemColor::ActiveText => Color::srgb_u8(0xff, 0x00, 0x00),
+        SystemColor::Mark => Color::srgb_u8(0xff, 0xff, 0x00),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::html_ui_builder::css::CssFontFace;
+    use crate::html_ui_builder::element::{TextBlock, UIElement};
+    use crate::html_ui_builder::font::FontRegistry;
+    use lightningcss::stylesheet::{ParserOptions, StyleSheet};
+    use scraper::Html;
+
+    fn computed_for(css: &str, html: &str, target: &str) -> HashMap<String, CssPropertyValue> {
+        let stylesheet = StyleSheet::parse(css, ParserOptions::default()).unwrap();
+        let stylesheet = CssStyleSheet::from_lightningcss(stylesheet);
+        let document = Html::parse_document(html);
+        let selector = scraper::Selector::parse(target).unwrap();
+        let element = document.select(&selector).next().unwrap();
+        let root_font_size = root_font_size(&element, &stylesheet);
+        compute_element_styles_with_ancestors(&element, &stylesheet, root_font_size)
+    }
+
+    fn width(properties: &HashMap<String, CssPropertyValue>) -> f32 {
+        match properties.get("width") {
+            Some(CssPropertyValue::Length(CssLength::Val(Val::Px(value)))) => *value,
+            other => panic!("unexpected width: {:?}", other),
+        }
+    }
+
+    const HTML: &str = r#"<div class="card" id="main"><p class="note big">text</p></div>"#;
+
+    #[test]
+    fn higher_specificity_wins_regardless_of_order() {
+        let css = ".card .note { width: 20px; } p { width: 10px; }";
+        assert_eq!(width(&computed_for(css, HTML, "p")), 20.0);
+
+        let css = "#main p { width: 30px; } .card .note.big { width: 20px; }";
+        assert_eq!(width(&computed_for(css, HTML, "p")), 30.0);
+    }
+
+    #[test]
+    fn later_rule_wins_on_specificity_tie() {
+        let css = ".note { width: 10px; } .big { width: 20px; }";
+        assert_eq!(width(&computed_for(css, HTML, "p")), 20.0);
+
+        let css = ".big { width: 20px; } .note { width: 10px; }";
+        assert_eq!(width(&computed_for(css, HTML, "p")), 10.0);
+    }
+
+    #[test]
+    fn selector_list_uses_most_specific_matching_selector() {
+        let css = "#main p, p { width: 30px; } .card p { width: 20px; }";
+        assert_eq!(width(&computed_for(css, HTML, "p")), 30.0);
+    }
+
+    #[test]
+    fn important_overrides_more_specific_rules() {
+        let css = "p { width: 10px !important; } #main .note { width: 30px; }";
+        assert_eq!(width(&computed_for(css, HTML, "p")), 10.0);
+    }
+
+    fn font_size(properties: &HashMap<String, CssPropertyValue>) -> Option<f32> {
+        match properties.get("font-size") {
+            Some(CssPropertyValue::Size(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn state_rules_only_apply_in_matching_state() {
+        let css =
+            ".note { width: 10px; } .note:hover { width: 20px; } .note:active { width: 30px; }";
+        let stylesheet = StyleSheet::parse(css, ParserOptions::default()).unwrap();
+        let stylesheet = CssStyleSheet::from_lightningcss(stylesheet);
+        let document = Html::parse_document(HTML);
+        let selector = scraper::Selector::parse("p").unwrap();
+        let element = document.select(&selector).next().unwrap();
+        let in_state = |state| {
+            compute_element_styles_in_state(&element, &stylesheet, None, state, DEFAULT_FONT_SIZE)
+        };
+
+        assert!(has_state_styles(&element, &stylesheet));
+        assert_eq!(width(&in_state(ElementState::default())), 10.0);
+        let hover = ElementState {
+            hover: true,
+            ..default()
+        };
+        assert_eq!(width(&in_state(hover)), 20.0);
+        let pressed = ElementState {
+            hover: true,
+            active: true,
+            ..default()
+        };
+        assert_eq!(width(&in_state(pressed)), 30.0);
+    }
+
+    #[test]
+    fn state_pseudo_classes_only_apply_to_the_subject() {
+        let css = ".card:hover p { width: 20px; } .card:focus > .note { width: 30px; } p { width: 10px; }";
+        let stylesheet = StyleSheet::parse(css, ParserOptions::default()).unwrap();
+        let stylesheet = CssStyleSheet::from_lightningcss(stylesheet);
+        assert!(stylesheet.rules[0].selectors.is_empty());
+        assert!(stylesheet.rules[1].selectors.is_empty());
+
+        let document = Html::parse_document(HTML);
+        let selector = scraper::Selector::parse("p").unwrap();
+        let element = document.select(&selector).next().unwrap();
+        assert!(!has_state_styles(&element, &stylesheet));
+        let computed = compute_element_styles_in_state(
+            &element,
+            &stylesheet,
+            None,
+            ElementState::ALL,
+            DEFAULT_FONT_SIZE,
+        );
+        assert_eq!(width(&computed), 10.0);
+    }
+
+    #[test]
+    fn flexbox_properties_map_to_node() {
+        let css = "p { display: flex; flex-flow: column wrap; justify-content: space-between;
+            align-items: center; align-self: flex-end; flex: 2 0 50%; gap: 4px 10%; }";
+        let node = convert_css_to_bevy_style(&computed_for(css, HTML, "p"));
+        assert_eq!(node.display, Display::Flex);
+        assert_eq!(node.flex_direction, FlexDirection::Column);
+        assert_eq!(node.flex_wrap, FlexWrap::Wrap);
+        assert_eq!(node.justify_content, JustifyContent::SpaceBetween);
+        assert_eq!(node.align_items, AlignItems::Center);
+        assert_eq!(node.align_self, AlignSelf::FlexEnd);
+        assert_eq!(node.flex_grow, 2.0);
+        assert_eq!(node.flex_shrink, 0.0);
+        assert_eq!(node.flex_basis, Val::Percent(50.0));
+        assert_eq!(node.row_gap, Val::Px(4.0));
+        assert_eq!(node.column_gap, Val::Percent(10.0));
+
+        // browser defaults when unspecified
+        let node = convert_css_to_bevy_style(&computed_for("", HTML, "p"));
+        assert_eq!(node.flex_direction, FlexDirection::Row);
+        assert_eq!(node.align_items, AlignItems::Default);
+    }
+
+    #[test]
+    fn grid_properties_map_to_node() {
+        let css =
+            "div { display: grid; grid-template-columns: 100px repeat(2, minmax(10px, 1fr)) auto;
+            grid-template-rows: 20% 2fr; grid-auto-rows: 50px; grid-auto-flow: column dense; }
+            p { grid-column: 2 / span 3; grid-row: span 2 / 4; }";
+        let node = convert_css_to_bevy_style(&computed_for(css, HTML, "div"));
+        assert_eq!(node.display, Display::Grid);
+        assert_eq!(
+            node.grid_template_columns,
+            vec![
+                GridTrack::px(100.0),
+                RepeatedGridTrack::repeat_many(
+                    2,
+                    vec![GridTrack::minmax(
+                        MinTrackSizingFunction::Px(10.0),
+                        MaxTrackSizingFunction::Fraction(1.0),
+                    )],
+                ),
+                GridTrack::auto(),
+            ]
+        );
+        assert_eq!(
+            node.grid_template_rows,
+            vec![GridTrack::percent(20.0), GridTrack::fr(2.0)]
+        );
+        assert_eq!(node.grid_auto_rows, vec![GridTrack::px(50.0)]);
+        assert_eq!(node.grid_auto_flow, GridAutoFlow::ColumnDense);
+
+        let node = convert_css_to_bevy_style(&computed_for(css, HTML, "p"));
+        assert_eq!(node.grid_column, GridPlacement::start_span(2, 3));
+        assert_eq!(node.grid_row, GridPlacement::end(4).set_span(2));
+    }
+
+    #[test]
+    fn positioning_properties() {
+        let css = "p { position: absolute; top: 10px; left: 5%; z-index: 3; }
+            div { top: 10px; z-index: 2; position: fixed; }";
+        let computed = computed_for(css, HTML, "p");
+        let node = convert_css_to_bevy_style(&computed);
+        assert_eq!(node.position_type, PositionType::Absolute);
+        assert_eq!(node.top, Val::Px(10.0));
+        assert_eq!(node.left, Val::Percent(5.0));
+        assert_eq!(node.right, Val::Auto);
+        assert_eq!(extract_z_index(&computed), (Some(ZIndex(3)), None));
+
+        let computed = computed_for(css, HTML, "div");
+        assert_eq!(extract_z_index(&computed), (None, Some(GlobalZIndex(2))));
+
+        // static ignores inset properties
+        let node = convert_css_to_bevy_style(&computed_for("p { top: 10px; }", HTML, "p"));
+        assert_eq!(node.position_type, PositionType::Relative);
+        assert_eq!(node.top, Val::Auto);
+    }
+
+    #[test]
+    fn sizes_keep_their_units() {
+        let css = "p { width: 50%; height: auto; min-width: 10vw; max-width: 80vmin;
+            min-height: fit-content; max-height: none; aspect-ratio: 16 / 9; }";
+        let node = convert_css_to_bevy_style(&computed_for(css, HTML, "p"));
+        assert_eq!(node.width, Val::Percent(50.0));
+        assert_eq!(node.height, Val::Auto);
+        assert_eq!(node.min_width, Val::Vw(10.0));
+        assert_eq!(node.max_width, Val::VMin(80.0));
+        assert_eq!(node.min_height, Val::Auto);
+        assert_eq!(node.max_height, Val::Auto);
+        assert_eq!(node.aspect_ratio, Some(16.0 / 9.0));
+
+        let node = convert_css_to_bevy_style(&computed_for("p { height: 2in; }", HTML, "p"));
+        assert_eq!(node.height, Val::Px(192.0));
+    }
+
+    #[test]
+    fn inherited_properties_flow_to_descendants() {
+        let css = ".card { font-size: 20px; width: 50px; color: white; }";
+        let computed = computed_for(css, HTML, "p");
+        assert_eq!(font_size(&computed), Some(20.0));
+        assert!(computed.contains_key("color"));
+        // width is not inherited
+        assert!(!computed.contains_key("width"));
+
+        let css = ".card { letter-spacing: 2px; }";
+        assert!(matches!(
+            computed_for(css, HTML, "p").get("letter-spacing"),
+            Some(CssPropertyValue::Length(CssLength::Val(Val::Px(2.0))))
+        ));
+    }
+
+    #[test]
+    fn css_wide_keywords() {
+        let css = ".card { font-size: 20px; width: 50px; } p { font-size: initial; }";
+        assert_eq!(font_size(&computed_for(css, HTML, "p")), None);
+
+        let css = ".card { width: 50px; } p { width: inherit; }";
+        assert_eq!(width(&computed_for(css, HTML, "p")), 50.0);
+
+        let css = ".card { width: 50px; font-size: 20px; } p { width: unset; font-size: unset; }";
+        let computed = computed_for(css, HTML, "p");
+        assert!(!computed.contains_key("width"));
+        assert_eq!(font_size(&computed), Some(20.0));
+
+        // keyword บน shorthand ใช้กับทุก longhand
+        let css = ".card { padding: 4px 8px; } p { padding: 1px; padding: inherit; }";
+        let node = convert_css_to_bevy_style(&computed_for(css, HTML, "p"));
+        assert_eq!(node.padding, UiRect::axes(Val::Px(8.0), Val::Px(4.0)));
+
+        let css = "p { margin: 10px; margin: initial; border: 2px solid red; border: unset; }";
+        let node = convert_css_to_bevy_style(&computed_for(css, HTML, "p"));
+        assert_eq!(node.margin, UiRect::default());
+        assert_eq!(node.border, UiRect::default());
+    }
+
+    #[test]
+    fn important_declarations_cascade_by_specificity_and_order() {
+        let css = ".note { width: 20px !important; } p { width: 10px !important; }";
+        assert_eq!(width(&computed_for(css, HTML, "p")), 20.0);
+
+        let css = ".note { width: 20px !important; } .big { width: 10px !important; }";
+        assert_eq!(width(&computed_for(css, HTML, "p")), 10.0);
+    }
+
+    #[test]
+    fn relative_units_resolve_against_font_size() {
+        let css = "html { font-size: 10px; } .card { font-size: 2em; } p { font-size: 150%; width: 2em; }";
+        let computed = computed_for(css, HTML, "p");
+        assert_eq!(font_size(&computed), Some(30.0));
+        assert_eq!(width(&computed), 60.0);
+
+        let css = "html { font-size: 10px; } .card { font-size: 20px; } p { width: 3rem; }";
+        assert_eq!(width(&computed_for(css, HTML, "p")), 30.0);
+
+        let css = "p { font-size: 20px; width: 4ch; padding: 1em; }";
+        let node = convert_css_to_bevy_style(&computed_for(css, HTML, "p"));
+        assert_eq!(node.width, Val::Px(40.0));
+        assert_eq!(node.padding.left, Val::Px(20.0));
+
+        let css = ".card { font-size: larger; } p { font-size: x-large; }";
+        assert_eq!(font_size(&computed_for(css, HTML, "p")), Some(24.0));
+    }
+
+    #[test]
+    fn math_functions_resolve_at_build_time_when_possible() {
+        let css = "p { font-size: 10px; width: calc(2em + 5px); height: min(50vw, calc(20vw * 2)); padding: clamp(1rem, 1px, 2rem); }";
+        let computed = computed_for(css, HTML, "p");
+        assert_eq!(width(&computed), 25.0);
+        let node = convert_css_to_bevy_style(&computed);
+        assert_eq!(node.height, Val::Vw(40.0));
+        assert_eq!(node.padding.top, Val::Px(16.0));
+        assert!(extract_calc_styles(&computed).is_empty());
+
+        // math function ที่ไม่รองรับไม่กลายเป็น 0px
+        let css = "p { width: round(up, 50%, 10px); }";
+        assert!(matches!(
+            computed_for(css, HTML, "p").get("width"),
+            Some(CssPropertyValue::Length(CssLength::Val(Val::Auto)))
+        ));
+    }
+
+    #[test]
+    fn mixed_unit_calc_is_evaluated_at_runtime() {
+        let css = "p { font-size: 10px; width: calc(100% - 2em); margin: max(10vw, 50px) 0; }";
+        let computed = computed_for(css, HTML, "p");
+        let node = convert_css_to_bevy_style(&computed);
+        assert_eq!(node.width, Val::Auto);
+
+        let calc_styles = extract_calc_styles(&computed);
+        let evaluate = |field: CalcField| {
+            let (_, calc) = calc_styles.0.iter().find(|(f, _)| *f == field).unwrap();
+            calc.evaluate(200.0, Vec2::new(1000.0, 500.0))
+        };
+        assert_eq!(evaluate(CalcField::Width), 180.0);
+        assert_eq!(evaluate(CalcField::MarginTop), 100.0);
+        assert_eq!(evaluate(CalcField::MarginBottom), 100.0);
+        assert_eq!(calc_styles.0.len(), 3);
+    }
+
+    #[test]
+    fn custom_properties_inherit_and_substitute() {
+        let css = ":root { --spacing: 12px; --primary: #ff0000; } .card { --spacing: 20px; } p { width: var(--spacing); color: var(--primary); }";
+        let computed = computed_for(css, HTML, "p");
+        assert_eq!(width(&computed), 20.0);
+        assert!(matches!(
+            computed.get("color"),
+            Some(CssPropertyValue::Color(_))
+        ));
+
+        let css = "p { --a: var(--b); --b: 5px; width: var(--a); padding: var(--missing, var(--b) 2px); }";
+        let computed = computed_for(css, HTML, "p");
+        assert_eq!(width(&computed), 5.0);
+        let node = convert_css_to_bevy_style(&computed);
+        assert_eq!(node.padding.top, Val::Px(5.0));
+        assert_eq!(node.padding.left, Val::Px(2.0));
+
+        // missing variable without fallback behaves like unset
+        let css =
+            ".card { font-size: 20px; } p { font-size: var(--missing); width: var(--missing); }";
+        let computed = computed_for(css, HTML, "p");
+        assert_eq!(font_size(&computed), Some(20.0));
+        assert!(!computed.contains_key("width"));
+
+        // cycles are invalid
+        let css = "p { --a: var(--b); --b: var(--a); width: var(--a, 7px); }";
+        assert_eq!(width(&computed_for(css, HTML, "p")), 7.0);
+    }
+
+    #[test]
+    fn shorthand_var_keeps_its_cascade_position() {
+        let node = |css: &str| convert_css_to_bevy_style(&computed_for(css, HTML, "p"));
+
+        let css = ":root { --p: 2px; } p { padding: var(--p); padding-left: 4px; }";
+        assert_eq!(
+            node(css).padding,
+            UiRect::new(Val::Px(4.0), Val::Px(2.0), Val::Px(2.0), Val::Px(2.0))
+        );
+
+        // longhand จาก rule ที่ specific กว่าชนะ shorthand ที่อยู่ทีหลัง
+        let css = ":root { --m: 3px; } .card .note { margin-top: 9px; } p { margin: var(--m); }";
+        assert_eq!(
+            node(css).margin,
+            UiRect::new(Val::Px(3.0), Val::Px(3.0), Val::Px(9.0), Val::Px(3.0))
+        );
+
+        // shorthand ทีหลังทับ longhand ก่อนหน้าทุกด้าน
+        let css = ":root { --p: 2px; } p { padding-left: 4px; padding: var(--p); }";
+        assert_eq!(node(css).padding, UiRect::all(Val::Px(2.0)));
+    }
+
+    #[test]
+    fn stylesheet_variables_override_root_declarations() {
+        let css = ":root { --size: 10px; } p { width: var(--size); }";
+        let stylesheet = StyleSheet::parse(css, ParserOptions::default()).unwrap();
+        let mut stylesheet = CssStyleSheet::from_lightningcss(stylesheet);
+        stylesheet
+            .variables
+            .insert("--size".to_string(), "42px".to_string());
+        let document = Html::parse_document(HTML);
+        let selector = scraper::Selector::parse("p").unwrap();
+        let element = document.select(&selector).next().unwrap();
+        let computed =
+            compute_element_styles_with_ancestors(&element, &stylesheet, DEFAULT_FONT_SIZE);
+        assert_eq!(width(&computed), 42.0);
+    }
+
+    fn background_of(color: &str) -> Srgba {
+        let css = format!("p {{ color: #102030; background-color: {color}; }}");
+        extract_background_color(&computed_for(&css, HTML, "p"))
+            .0
+            .to_srgba()
+    }
+
+    fn assert_color(color: &str, expected: Srgba) {
+        let actual = background_of(color);
+        let close = |a: f32, b: f32| (a - b).abs() < 0.01;
+        assert!(
+            close(actual.red, expected.red)
+                && close(actual.green, expected.green)
+                && close(actual.blue, expected.blue)
+                && close(actual.alpha, expected.alpha),
+            "{color}: expected {expected:?}, got {actual:?}"
+        );
+    }
+
+    #[test]
+    fn named_hex_and_rgb_colors() {
+        assert_color("rebeccapurple", Srgba::rgb_u8(102, 51, 153));
+        assert_color("#ff000080", Srgba::new(1.0, 0.0, 0.0, 128.0 / 255.0));
+        assert_color("rgb(0 128 255)", Srgba::rgb_u8(0, 128, 255));
+        assert_color("rgb(0 none 255)", Srgba::rgb_u8(0, 0, 255));
+        assert_color("transparent", Srgba::NONE);
+    }
+
+    #[test]
+    fn hsl_and_hwb_colors() {
+        assert_color("hsl(120 100% 25%)", Srgba::rgb_u8(0, 128, 0));
+        assert_color("hsl(240deg 100% 50% / 0.5)", Srgba::new(0.0, 0.0, 1.0, 0.5));
+        assert_color("hwb(0 0% 0%)", Srgba::RED);
+        assert_color("hwb(120 20% 20%)", Srgba::rgb_u8(51, 204, 51));
+    }
+
+    #[test]
+    fn lab_and_lch_colors() {
+        assert_color("lab(100 0 0)", Srgba::WHITE);
+        assert_color("lab(54.29 80.8 69.89)", Srgba::RED);
+        assert_color("lch(54.29 106.84 40.85)", Srgba::RED);
+    }
+
+    #[test]
+    fn oklab_and_oklch_colors() {
+        assert_color("oklab(0.628 0.2249 0.1258)", Srgba::RED);
+        assert_color("oklch(0.628 0.2577 29.23)", Srgba::RED);
+        assert_color("oklch(1 0 0 / 50%)", Srgba::new(1.0, 1.0, 1.0, 0.5));
+    }
+
+    #[test]
+    fn predefined_color_spaces() {
+        assert_color("color(srgb 0 0.5 1)", Srgba::new(0.0, 0.5, 1.0, 1.0));
+        assert_color("color(srgb-linear 1 0 0)", Srgba::RED);
+        assert_color("color(display-p3 1 1 1)", Srgba::WHITE);
+        assert_color("color(rec2020 0 0 0)", Srgba::BLACK);
+        assert_color("color(xyz-d65 0.9505 1 1.089)", Srgba::WHITE);
+        assert_color("color(xyz-d50 0.9642 1 0.8252)", Srgba::WHITE);
+    }
+
+    #[test]
+    fn color_mix() {
+        assert_color(
+            "color-mix(in srgb, red, blue)",
+            Srgba::new(0.5, 0.0, 0.5, 1.0),
+        );
+        assert_color(
+            "color-mix(in srgb, white 25%, black)",
+            Srgba::new(0.25, 0.25, 0.25, 1.0),
+        );
+    }
+
+    #[test]
+    fn current_color_uses_element_color() {
+        assert_color("currentColor", Srgba::rgb_u8(0x10, 0x20, 0x30));
+
+        let css =
+            ".card { color: red; } p { color: currentColor; background-color: currentColor; }";
+        let computed = computed_for(css, HTML, "p");
+        assert_eq!(extract_text_color(&computed).to_srgba(), Srgba::RED);
+        assert_eq!(extract_background_color(&computed).0.to_srgba(), Srgba::RED);
+    }
+
+    #[test]
+    fn system_and_light_dark_colors() {
+        assert_color("Canvas", Srgba::WHITE);
+        assert_color("CanvasText", Srgba::BLACK);
+        assert_color("LinkText", Srgba::rgb_u8(0, 0, 0xee));
+        assert_color("light-dark(white, black)", Srgba::WHITE);
+    }
+
+    #[test]
+    fn borders_map_to_node_border_and_border_color() {
+        let css = "p { border: 2px solid red; border-left-width: 4px; border-bottom-style: none; }";
+        let computed = computed_for(css, HTML, "p");
+        let node = convert_css_to_bevy_style(&computed);
+        assert_eq!(node.border.top, Val::Px(2.0));
+        assert_eq!(node.border.left, Val::Px(4.0));
+        assert_eq!(node.border.bottom, Val::Px(0.0));
+        assert_eq!(extract_border_color(&computed).0.to_srgba(), Srgba::RED);
+
+        // no style → no border, even with a width
+        let css = "p { border-width: 5px; }";
+        let node = convert_css_to_bevy_style(&computed_for(css, HTML, "p"));
+        assert_eq!(node.border, UiRect::all(Val::Px(0.0)));
+
+        // border-color defaults to currentColor, medium is 3px
+        let css = "p { color: blue; border-style: solid; border-inline-start-width: thin; }";
+        let computed = computed_for(css, HTML, "p");
+        let node = convert_css_to_bevy_style(&computed);
+        assert_eq!(node.border.top, Val::Px(3.0));
+        assert_eq!(node.border.left, Val::Px(1.0));
+        assert_eq!(extract_border_color(&computed).0.to_srgba(), Srgba::BLUE);
+    }
+
+    #[test]
+    fn box_shadows() {
+        let css = "p { color: blue; box-shadow: 0 2px 4px rgba(0,0,0,0.5), 1em 0 0 2px, inset 0 0 3px red; font-size: 10px; }";
+        let shadow = extract_box_shadow(&computed_for(css, HTML, "p"));
+        assert_eq!(shadow.len(), 2);
+        assert_eq!(shadow[0].y_offset, Val::Px(2.0));
+        assert_eq!(shadow[0].blur_radius, Val::Px(4.0));
+        assert!((shadow[0].color.alpha() - 0.5).abs() < 0.01);
+        assert_eq!(shadow[1].x_offset, Val::Px(10.0));
+        assert_eq!(shadow[1].spread_radius, Val::Px(2.0));
+        // color defaults to currentColor
+        assert_eq!(shadow[1].color.to_srgba(), Srgba::BLUE);
+
+        let shadow = extract_box_shadow(&computed_for("p { box-shadow: none; }", HTML, "p"));
+        assert!(shadow.is_empty());
+    }
+
+    #[test]
+    fn outlines() {
+        let css = "p { color: red; outline: 2px solid; outline-offset: 0.5em; font-size: 10px; }";
+        let outline = extract_outline(&computed_for(css, HTML, "p")).unwrap();
+        assert_eq!(outline.width, Val::Px(2.0));
+        assert_eq!(outline.offset, Val::Px(5.0));
+        assert_eq!(outline.color.to_srgba(), Srgba::RED);
+
+        let css = "p { outline: 2px solid blue; } .card p { outline-style: none; }";
+        assert!(extract_outline(&computed_for(css, HTML, "p")).is_none());
+
+        let css = "p { outline-style: auto; outline-color: blue; }";
+        let outline = extract_outline(&computed_for(css, HTML, "p")).unwrap();
+        assert_eq!(outline.width, Val::Px(3.0));
+        assert_eq!(outline.color.to_srgba(), Srgba::BLUE);
+    }
+
+    #[test]
+    fn background_images() {
+        let css = r#"p { background: #fff url("./images/panel.png") no-repeat; background-size: cover; }"#;
+        let computed = computed_for(css, HTML, "p");
+        assert_eq!(
+            extract_background_color(&computed).0.to_srgba(),
+            Srgba::WHITE
+        );
+        assert_eq!(
+            extract_background_layers(&computed),
+            vec![BackgroundLayer::Image {
+                path: "images/panel.png".to_string(),
+                fit: ImageFit::Cover,
+            }]
+        );
+
+        // first layer is on top → painted last; auto size tiles by default
+        let css = "p { background-image: linear-gradient(red, blue), url(a.png); font-size: 10px; } \
+                   .card p { background-size: auto, 2em auto; }";
+        let layers = extract_background_layers(&computed_for(css, HTML, "p"));
+        assert_eq!(layers.len(), 2);
+        assert!(matches!(
+            &layers[0],
+            BackgroundLayer::Image {
+                fit: ImageFit::Size(Val::Px(20.0), Val::Auto),
+                ..
+            }
+        ));
+        assert!(matches!(&layers[1], BackgroundLayer::Gradient(_)));
+
+        let css = "p { background-image: url(a.png); background-repeat: repeat-x; }";
+        assert!(matches!(
+            &extract_background_layers(&computed_for(css, HTML, "p"))[0],
+            BackgroundLayer::Image {
+                fit: ImageFit::Repeat { x: true, y: false },
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn border_image_and_object_fit() {
+        let css = "p { border-image: url(frame.png) 10 20% round; object-fit: contain; }";
+        let computed = computed_for(css, HTML, "p");
+        assert_eq!(
+            extract_background_layers(&computed),
+            vec![BackgroundLayer::Image {
+                path: "frame.png".to_string(),
+                fit: ImageFit::Sliced {
+                    slices: [
+                        Val::Px(10.0),
+                        Val::Percent(20.0),
+                        Val::Px(10.0),
+                        Val::Percent(20.0)
+                    ],
+                    tile: true,
+                },
+            }]
+        );
+        assert_eq!(extract_object_fit(&computed), ImageFit::Contain);
+        assert_eq!(extract_object_fit(&HashMap::new()), ImageFit::Stretch);
+    }
+
+    fn gradient_for(css: &str) -> BackgroundGradient {
+        match extract_background_layers(&computed_for(css, HTML, "p")).pop() {
+            Some(BackgroundLayer::Gradient(gradient)) => gradient,
+            other => panic!("unexpected layer: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn linear_gradients() {
+        let gradient =
+            gradient_for("p { background-image: linear-gradient(to right, red, blue 80%, lime); }");
+        assert_eq!(gradient.stops[0].0.to_srgba(), Srgba::RED);
+        let material = gradient.material(Vec2::new(200.0, 100.0));
+        assert_eq!((material.kind, material.count), (0, 3));
+        assert!(
+            material
+                .geometry
+                .abs_diff_eq(Vec4::new(1.0, 0.0, 200.0, 0.0), 1e-4)
+        );
+        assert!(material.offsets[0].abs_diff_eq(Vec4::new(0.0, 0.8, 1.0, 0.0), 1e-4));
+
+        // px stops are relative to the gradient line; missing positions are spread evenly
+        let gradient = gradient_for(
+            "p { background: linear-gradient(180deg, red 20px, lime, blue, red 80px); }",
+        );
+        let material = gradient.material(Vec2::new(50.0, 100.0));
+        assert!(
+            material
+                .geometry
+                .abs_diff_eq(Vec4::new(0.0, 1.0, 100.0, 0.0), 1e-4)
+        );
+        assert!(material.offsets[0].abs_diff_eq(Vec4::new(0.2, 0.4, 0.6, 0.8), 1e-4));
+
+        // corner directions depend on the box's aspect ratio
+        let gradient = gradient_for(
+            "p { background-image: repeating-linear-gradient(to top right, red, blue 10%); }",
+        );
+        let material = gradient.material(Vec2::new(100.0, 50.0));
+        assert_eq!(material.repeating, 1);
+        let direction = Vec2::new(50.0, -100.0).normalize();
+        assert!(material.geometry.xy().abs_diff_eq(direction, 1e-4));
+    }
+
+    #[test]
+    fn radial_gradients() {
+        let gradient =
+            gradient_for("p { background-image: radial-gradient(circle at 25% 50%, red, blue); }");
+        let material = gradient.material(Vec2::new(100.0, 100.0));
+        assert_eq!(material.kind, 1);
+        // farthest-corner from (25, 50) is (100, 0) or (100, 100)
+        let radius = Vec2::new(75.0, 50.0).length();
+        assert!(
+            material
+                .geometry
+                .abs_diff_eq(Vec4::new(-25.0, 0.0, radius, radius), 1e-3)
+        );
+
+        let gradient =
+            gradient_for("p { background-image: radial-gradient(closest-side, red, blue); }");
+        let material = gradient.material(Vec2::new(200.0, 100.0));
+        assert!(
+            material
+                .geometry
+                .abs_diff_eq(Vec4::new(0.0, 0.0, 100.0, 50.0), 1e-3)
+        );
+    }
+
+    #[test]
+    fn visibility_opacity_and_display_none() {
+        let css = ".card { visibility: hidden; opacity: 0.5; } p { display: none; }";
+        let card = computed_for(css, HTML, "div");
+        let p = computed_for(css, HTML, "p");
+        assert_eq!(
+            extract_visibility(&card, &HashMap::new()),
+            Visibility::Hidden
+        );
+        // inherited hidden follows the parent's Visibility so toggling the parent shows both
+        assert_eq!(extract_visibility(&p, &card), Visibility::Inherited);
+        assert_eq!(extract_opacity(&card), 0.5);
+        // opacity itself is not inherited; the builder multiplies it down the subtree
+        assert_eq!(extract_opacity(&p), 1.0);
+        assert_eq!(convert_css_to_bevy_style(&p).display, Display::None);
+
+        let css = ".card { visibility: collapse; } p { visibility: visible; opacity: 150%; }";
+        let card = computed_for(css, HTML, "div");
+        let p = computed_for(css, HTML, "p");
+        assert_eq!(extract_visibility(&p, &card), Visibility::Visible);
+        assert_eq!(extract_opacity(&p), 1.0);
+    }
+
+    #[test]
+    fn overflow_and_clip_margin() {
+        let node = |css: &str| convert_css_to_bevy_style(&computed_for(css, HTML, "p"));
+        let overflow = |x, y| Overflow { x, y };
+
+        assert_eq!(node("p {}").overflow, Overflow::visible());
+        assert_eq!(
+            node("p { overflow: hidden; }").overflow,
+            overflow(OverflowAxis::Hidden, OverflowAxis::Hidden)
+        );
+        // visible next to a scrollable axis computes to auto, clip to hidden
+        assert_eq!(
+            node("p { overflow-y: auto; }").overflow,
+            overflow(OverflowAxis::Scroll, OverflowAxis::Scroll)
+        );
+        let clipped = node(
+            "p { overflow-x: clip; overflow-y: scroll; overflow-clip-margin: content-box 4px; }",
+        );
+        assert_eq!(
+            clipped.overflow,
+            overflow(OverflowAxis::Hidden, OverflowAxis::Scroll)
+        );
+        assert_eq!(
+            clipped.overflow_clip_margin,
+            OverflowClipMargin {
+                visual_box: OverflowClipBox::ContentBox,
+                margin: 4.0,
+            }
+        );
+
+        let clipped = node("p { overflow: clip; overflow-clip-margin: 1em; font-size: 10px; }");
+        assert_eq!(clipped.overflow, Overflow::clip());
+        assert_eq!(
+            clipped.overflow_clip_margin,
+            OverflowClipMargin {
+                visual_box: OverflowClipBox::PaddingBox,
+                margin: 10.0,
+            }
+        );
+    }
+
+    #[test]
+    fn padding_and_margin_longhands() {
+        let node = |css: &str| convert_css_to_bevy_style(&computed_for(css, HTML, "p"));
+
+        // longhand หลัง shorthand ทับเฉพาะด้านนั้น, shorthand ทีหลังทับทุกด้าน
+        let spaced = node("p { padding: 4px; padding-left: 8px; padding-block: 1px 2px; }");
+        assert_eq!(
+            spaced.padding,
+            UiRect::new(Val::Px(8.0), Val::Px(4.0), Val::Px(1.0), Val::Px(2.0))
+        );
+        let spaced = node("p { padding-top: 9px; padding-inline-end: 3%; padding: 5px; }");
+        assert_eq!(spaced.padding, UiRect::all(Val::Px(5.0)));
+
+        let centered = node("p { margin: 10px auto; margin-bottom: 0; }");
+        assert_eq!(
+            centered.margin,
+            UiRect::new(Val::Auto, Val::Auto, Val::Px(10.0), Val::Px(0.0))
+        );
+        let centered = node("p { margin-inline: auto; margin-block-start: 2em; font-size: 10px; }");
+        assert_eq!(
+            centered.margin,
+            UiRect::new(Val::Auto, Val::Auto, Val::Px(20.0), Val::Px(0.0))
+        );
+    }
+
+    #[test]
+    fn font_family_weight_and_style_resolve_to_registered_faces() {
+        let registry = FontRegistry::default();
+        let resolve = |css: &str| {
+            let font = extract_font(&computed_for(css, HTML, "p"));
+            registry.resolve(&font).map(|face| face.path.clone())
+        };
+        let prompt = |file: &str| Some(format!("fonts/Prompt-{file}.ttf"));
+
+        // family ที่ไม่รู้จักและ generic family ตกไปที่ fallback
+        assert_eq!(resolve("p {}"), prompt("Regular"));
+        assert_eq!(
+            resolve(r#"p { font-family: "Open Sans", sans-serif; font-weight: bold; }"#),
+            prompt("Bold")
+        );
+        // สืบทอดจาก parent และ bolder คำนวณจาก parent
+        assert_eq!(
+            resolve(".card { font-weight: 500; font-style: italic; }"),
+            prompt("MediumItalic")
+        );
+        assert_eq!(
+            resolve(".card { font-weight: 500; } p { font-weight: bolder; }"),
+            prompt("Bold")
+        );
+        // weight ที่ไม่มีไฟล์ตรง: 450 → 500, 600 → 700, 300 → 400, 900 → 700
+        assert_eq!(resolve("p { font-weight: 450; }"), prompt("Medium"));
+        assert_eq!(
+            resolve("p { font-weight: 600; font-style: oblique; }"),
+            prompt("BoldItalic")
+        );
+        assert_eq!(resolve("p { font-weight: 300; }"), prompt("Regular"));
+        assert_eq!(resolve("p { font-weight: 900; }"), prompt("Bold"));
+
+        let mut registry = FontRegistry::empty();
+        registry.register("Mono", "fonts/Mono.ttf", 400.0, false);
+        let font = extract_font(&computed_for(
+            "p { font-family: mono; font-style: italic; font-weight: 700; }",
+            HTML,
+            "p",
+        ));
+        // ไม่มี italic / bold ก็ใช้ไฟล์ที่มี
+        assert_eq!(
+            registry.resolve(&font).map(|face| face.path.as_str()),
+            Some("fonts/Mono.ttf")
+        );
+        assert_eq!(registry.resolve(&FontQuery::default()), None);
+    }
+
+    #[test]
+    fn font_face_rules_register_fonts() {
+        let css = r#"
+            @font-face { font-family: "Brand Sans"; src: url(./fonts/Brand.woff2) format("woff2"), url(fonts/Brand.ttf) format("truetype"); }
+            @font-face { font-family: Brand Sans; src: url(/fonts/Brand-BoldItalic.otf); font-weight: 600 800; font-style: oblique; }
+            @font-face { font-family: Web; src: url(fonts/Web.woff2), local(Web); }
+            @font-face { src: url(fonts/NoFamily.ttf); }
+            p { font-family: "brand sans", Prompt; font-weight: bold; font-style: italic; }
+        "#;
+        let stylesheet = StyleSheet::parse(css, ParserOptions::default()).unwrap();
+        let stylesheet = CssStyleSheet::from_lightningcss(stylesheet);
+        assert_eq!(
+            stylesheet.font_faces,
+            vec![
+                CssFontFace {
+                    family: "Brand Sans".to_string(),
+                    src: "fonts/Brand.ttf".to_string(),
+                    weight: 400.0,
+                    italic: false,
+                },
+                CssFontFace {
+                    family: "Brand Sans".to_string(),
+                    src: "fonts/Brand-BoldItalic.otf".to_string(),
+                    weight: 600.0,
+                    italic: true,
+                },
+            ]
+        );
+
+        let mut registry = FontRegistry::default();
+        for face in &stylesheet.font_faces {
+            registry.register(&face.family, face.src.clone(), face.weight, face.italic);
+        }
+        let font = extract_font(&computed_for(css, HTML, "p"));
+        assert_eq!(
+            registry.resolve(&font).map(|face| face.path.as_str()),
+            Some("fonts/Brand-BoldItalic.otf")
+        );
+    }
+
+    #[test]
+    fn text_align_line_height_and_white_space() {
+        let properties = computed_for(
+            ".card { text-align: center; line-height: 1.5; white-space: nowrap; }",
+            HTML,
+            "p",
+        );
+        let layout = extract_text_layout(&properties);
+        assert_eq!(layout.justify, JustifyText::Center);
+        assert_eq!(layout.linebreak, LineBreak::NoWrap);
+        assert!(matches!(
+            extract_line_height(&properties),
+            LineHeight::RelativeToFont(height) if height == 1.5
+        ));
+        assert_eq!(
+            apply_white_space("\n  Press   start\n  to play ", &properties),
+            "Press start to play"
+        );
+
+        let properties = computed_for(
+            "p { text-align: end; line-height: 2em; font-size: 10px; white-space: pre-line; }",
+            HTML,
+            "p",
+        );
+        let layout = extract_text_layout(&properties);
+        assert_eq!(layout.justify, JustifyText::Right);
+        assert_eq!(layout.linebreak, LineBreak::WordBoundary);
+        assert!(matches!(
+            extract_line_height(&properties),
+            LineHeight::Px(height) if height == 20.0
+        ));
+        assert_eq!(
+            apply_white_space("\n  Press   start\n  to play ", &properties),
+            "Press start\nto play"
+        );
+
+        let properties = computed_for("p { white-space: pre; }", HTML, "p");
+        assert_eq!(
+            extract_text_layout(&properties).linebreak,
+            LineBreak::NoWrap
+        );
+        assert_eq!(apply_white_space(" a  b\n", &properties), " a  b\n");
+        assert_eq!(apply_white_space(" \n ", &properties), "");
+        assert!(matches!(
+            extract_line_height(&properties),
+            LineHeight::RelativeToFont(height) if height == 1.2
+        ));
+    }
+
+    #[test]
+    fn text_overflow_ellipsis_needs_clipped_overflow() {
+        let ellipsis = |css: &str| extract_text_ellipsis(&computed_for(css, HTML, "p"));
+        assert!(ellipsis(
+            "p { text-overflow: ellipsis; overflow: hidden; white-space: nowrap; }"
+        ));
+        assert!(ellipsis("p { text-overflow: ellipsis; overflow-x: clip; }"));
+        assert!(ellipsis("p { text-overflow: ellipsis; overflow-y: auto; }"));
+        assert!(!ellipsis(
+            "p { text-overflow: ellipsis; overflow-y: clip; }"
+        ));
+        assert!(!ellipsis("p { text-overflow: ellipsis; }"));
+        assert!(!ellipsis("p { text-overflow: clip; overflow: hidden; }"));
+        // text-overflow ไม่สืบทอด
+        assert!(!ellipsis(
+            ".card { text-overflow: ellipsis; } p { overflow: hidden; }"
+        ));
+    }
+
+    #[test]
+    fn inline_elements_become_text_runs() {
+        let element = |css: &str, html: &str| {
+            let stylesheet = StyleSheet::parse(css, ParserOptions::default()).unwrap();
+            let stylesheet = Some(Box::new(CssStyleSheet::from_lightningcss(stylesheet)));
+            let document = Html::parse_document(html);
+            let selector = scraper::Selector::parse("body > *").unwrap();
+            UIElement::from_html_element_with_children(
+                &document.select(&selector).next().unwrap(),
+                &stylesheet,
+                DEFAULT_FONT_SIZE,
+            )
+        };
+        let texts = |block: &TextBlock| {
+            block
+                .runs
+                .iter()
+                .map(|run| run.text.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let css =
+            "p { color: black; } b { font-weight: bold; } .hot { color: red; font-size: 20px; }";
+        let p = element(
+            css,
+            "<p>\n  Press <b>Start</b>\n  to <span class=\"hot\"> play </span> now  </p>",
+        );
+        assert!(p.children.is_empty());
+        assert_eq!(p.text, "Press Start to play now");
+        assert_eq!(p.text_blocks.len(), 1);
+        let runs = &p.text_blocks[0].runs;
+        assert_eq!(
+            texts(&p.text_blocks[0]),
+            ["Press ", "Start", " to ", "play ", "now"]
+        );
+        // สีเดียวกับ block = None, สีของตัวเอง = Some
+        assert_eq!(runs[1].color, None);
+        assert_eq!(runs[1].font.weight, 700.0);
+        assert_eq!(runs[3].color, Some(Color::srgb(1.0, 0.0, 0.0)));
+        assert_eq!(runs[3].font_size, 20.0);
+
+        // block children แบ่ง inline content เป็นหลาย text blocks ตามตำแหน่ง
+        let div = element(
+            "a { display: block; } i { display: none; }",
+            "<div>Hi <em>there</em> <a>link</a> again<br> next <i>hidden</i></div>",
+        );
+        // display: none ไม่ใช่ inline จึงเป็น node ที่ไม่แสดง
+        assert_eq!(div.children.len(), 2);
+        assert_eq!(div.children[0].text, "link");
+        assert_eq!(div.children[1].computed_style.display, Display::None);
+        assert_eq!(div.text_blocks.len(), 2);
+        assert_eq!(div.text_blocks[0].position, 0);
+        assert_eq!(texts(&div.text_blocks[0]), ["Hi ", "there"]);
+        assert_eq!(div.text_blocks[1].position, 1);
+        assert_eq!(texts(&div.text_blocks[1]), ["again", "\n", "next"]);
+
+        // link, tabindex และ element ที่มี state styles ยังเป็น node ที่ focus / hover ได้
+        let p = element("", r##"<p>x <a href="#">y</a></p>"##);
+        assert_eq!(p.children.len(), 1);
+        assert_eq!(p.children[0].tab_index(), Some(0));
+        assert_eq!(p.children[0].text, "y");
+        assert_eq!(texts(&p.text_blocks[0]), ["x"]);
+        let p = element(
+            "em:hover { color: red; }",
+            r#"<p><span>a <b tabindex="1">b</b></span> <em>c</em> <a>d</a></p>"#,
+        );
+        assert_eq!(p.children.len(), 2);
+        assert!(!p.children[1].state_styles.is_empty());
+        assert_eq!(texts(&p.text_blocks[0]), ["d"]);
     }
 }